@@ -0,0 +1,142 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+
+/// The name serde_json gives `RawValue`. Asking for it makes serde_json hand over a number as the
+/// text it was sent as, instead of parsing it into an `f64` first.
+const RAW_VALUE: &str = "$serde_json::private::RawValue";
+
+/// Decodes an optional amount from the digits Merge sent, so amounts wider than an `f64` keep
+/// every digit. Use with `#[serde(default, deserialize_with = "crate::amount::deserialize")]`.
+///
+/// Amounts decoded from anything but JSON text, e.g. a nested model buffered by an untagged
+/// enum, have already been read as an `f64`.
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionVisitor)
+}
+
+struct OptionVisitor;
+
+impl<'de> Visitor<'de> for OptionVisitor {
+    type Value = Option<Decimal>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal amount or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_newtype_struct(RAW_VALUE, AmountVisitor)
+            .map(Some)
+    }
+}
+
+struct AmountVisitor;
+
+impl<'de> Visitor<'de> for AmountVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a decimal amount")
+    }
+
+    // The raw JSON text, sent by serde_json as a map with a single entry.
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        map.next_key::<de::IgnoredAny>()?;
+        let raw: String = map.next_value()?;
+        self.visit_str(raw.trim().trim_matches('"'))
+    }
+
+    // Any other deserializer, which does not know about `RawValue`.
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Decimal::from_str(value)
+            .or_else(|_| Decimal::from_scientific(value))
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Decimal::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Float(value), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Line {
+        #[serde(default, deserialize_with = "deserialize")]
+        amount: Option<Decimal>,
+    }
+
+    fn amount(json: &str) -> Option<Decimal> {
+        serde_json::from_str::<Line>(json).unwrap().amount
+    }
+
+    #[test]
+    fn it_keeps_every_digit() {
+        assert_eq!(
+            amount("{\"amount\": 1234567890123.4567891}"),
+            Some(Decimal::from_str("1234567890123.4567891").unwrap())
+        );
+        assert_eq!(amount("{\"amount\": -42}"), Some(Decimal::from(-42)));
+        assert_eq!(amount("{\"amount\": 1.5e3}"), Some(Decimal::from(1500)));
+    }
+
+    #[test]
+    fn it_reads_strings_and_missing_amounts() {
+        assert_eq!(
+            amount("{\"amount\": \"0.1000000000000000055\"}"),
+            Some(Decimal::from_str("0.1000000000000000055").unwrap())
+        );
+        assert_eq!(amount("{\"amount\": null}"), None);
+        assert_eq!(amount("{}"), None);
+    }
+
+    #[test]
+    fn it_reads_amounts_from_a_value() {
+        let line: Line = serde_json::from_value(serde_json::json!({"amount": 1250.5})).unwrap();
+        assert_eq!(line.amount, Some(Decimal::from_str("1250.5").unwrap()));
+    }
+
+    #[test]
+    fn it_rejects_anything_else() {
+        assert!(serde_json::from_str::<Line>("{\"amount\": true}").is_err());
+        assert!(serde_json::from_str::<Line>("{\"amount\": \"ten\"}").is_err());
+    }
+}
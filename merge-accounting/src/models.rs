@@ -0,0 +1,18 @@
+pub mod accounts;
+pub mod addresses;
+pub mod balance_sheets;
+pub mod cash_flow_statements;
+pub mod company_info;
+pub mod contacts;
+pub mod credit_notes;
+pub mod expenses;
+pub mod income_statements;
+pub mod invoices;
+pub mod items;
+pub mod journal_entries;
+pub mod payments;
+pub mod purchase_orders;
+pub mod tax_rates;
+pub mod tracking_categories;
+pub mod transactions;
+pub mod vendor_credits;
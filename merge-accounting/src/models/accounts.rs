@@ -0,0 +1,277 @@
+use crate::configuration::AccountingConfig;
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AccountModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub classification: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub current_balance: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub parent_account: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="accounts", return_type=Paginated<AccountModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<AccountModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="accounts", return_type=AccountModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/accounts?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Cash\",
+                      \"description\": \"Cash\",
+                      \"classification\": \"ASSET\",
+                      \"type\": \"ACCOUNTS_RECEIVABLE\",
+                      \"status\": \"ACTIVE\",
+                      \"current_balance\": 1000.5,
+                      \"currency\": \"USD\",
+                      \"account_number\": \"X12Y9AB\",
+                      \"parent_account\": \"a38e4924-cc12-4f4a-92ce-a4999ca40bfe\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/accounts\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AccountModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Cash")
+            .description("Cash")
+            .classification("ASSET")
+            .r#type("ACCOUNTS_RECEIVABLE")
+            .status("ACTIVE")
+            .current_balance(Decimal::from_str("1000.5").unwrap())
+            .currency("USD")
+            .account_number("X12Y9AB")
+            .parent_account("a38e4924-cc12-4f4a-92ce-a4999ca40bfe")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/accounts")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/accounts/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Cash\",
+              \"description\": \"Cash\",
+              \"classification\": \"ASSET\",
+              \"type\": \"ACCOUNTS_RECEIVABLE\",
+              \"status\": \"ACTIVE\",
+              \"current_balance\": 1000.5,
+              \"currency\": \"USD\",
+              \"account_number\": \"X12Y9AB\",
+              \"parent_account\": \"a38e4924-cc12-4f4a-92ce-a4999ca40bfe\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/accounts\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AccountModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Cash")
+            .description("Cash")
+            .classification("ASSET")
+            .r#type("ACCOUNTS_RECEIVABLE")
+            .status("ACTIVE")
+            .current_balance(Decimal::from_str("1000.5").unwrap())
+            .currency("USD")
+            .account_number("X12Y9AB")
+            .parent_account("a38e4924-cc12-4f4a-92ce-a4999ca40bfe")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/accounts")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: AccountModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
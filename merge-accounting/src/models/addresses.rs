@@ -0,0 +1,116 @@
+use crate::configuration::AccountingConfig;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AddressModel {
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub street_1: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub street_2: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub city: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub state: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub country_subdivision: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub country: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub zip_code: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_at: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="addresses", return_type=AddressModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/addresses/9871b4a9-f5d2-4f3b-a66b-dfedbed42c46?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"type\": \"BILLING\",
+              \"street_1\": \"50 Bowling Green Dr\",
+              \"street_2\": \"Golden Gate Park\",
+              \"city\": \"San Francisco\",
+              \"state\": \"California\",
+              \"country_subdivision\": \"CA\",
+              \"country\": \"US\",
+              \"zip_code\": \"94122\",
+              \"created_at\": \"2021-12-06T10:11:26Z\",
+              \"modified_at\": \"2021-12-06T10:11:26Z\"
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AddressModelBuilder::default()
+            .r#type("BILLING")
+            .street_1("50 Bowling Green Dr")
+            .street_2("Golden Gate Park")
+            .city("San Francisco")
+            .state("California")
+            .country_subdivision("CA")
+            .country("US")
+            .zip_code("94122")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("9871b4a9-f5d2-4f3b-a66b-dfedbed42c46")
+            .build()
+            .unwrap();
+
+        let response: AddressModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,467 @@
+use crate::configuration::AccountingConfig;
+use crate::report::{section, Report, ReportItem};
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct BalanceSheetModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub date: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub net_assets: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub assets: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub liabilities: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub equity: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_generated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="balance-sheets", return_type=Paginated<BalanceSheetModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<BalanceSheetModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="balance-sheets", return_type=BalanceSheetModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+impl Report for BalanceSheetModel {
+    fn sections(&self) -> Vec<(&'static str, &[ReportItem])> {
+        vec![
+            ("assets", section(&self.assets)),
+            ("liabilities", section(&self.liabilities)),
+            ("equity", section(&self.equity)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::ReportItemBuilder;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/balance-sheets?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"BalanceSheet\",
+                      \"currency\": \"USD\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"date\": \"2021-09-30T00:00:00Z\",
+                      \"net_assets\": 1000,
+                      \"assets\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Current Assets\",
+                          \"value\": 1500.5,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Cash\",
+                              \"value\": 1000,
+                              \"sub_items\": [],
+                              \"company\": null
+                            },
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Receivables\",
+                              \"value\": 500.5,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"liabilities\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Current Liabilities\",
+                          \"value\": 500.5,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Payables\",
+                              \"value\": 500.5,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"equity\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Retained Earnings\",
+                          \"value\": 1000,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Profit\",
+                              \"value\": 1000,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/balance-sheets\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = BalanceSheetModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("BalanceSheet")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .date("2021-09-30T00:00:00Z")
+            .net_assets(Decimal::from_str("1000").unwrap())
+            .assets(vec![ReportItemBuilder::default()
+                .name("Current Assets")
+                .value(Decimal::from_str("1500.5").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Cash")
+                        .value(Decimal::from_str("1000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Receivables")
+                        .value(Decimal::from_str("500.5").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .liabilities(vec![ReportItemBuilder::default()
+                .name("Current Liabilities")
+                .value(Decimal::from_str("500.5").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Payables")
+                    .value(Decimal::from_str("500.5").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .equity(vec![ReportItemBuilder::default()
+                .name("Retained Earnings")
+                .value(Decimal::from_str("1000").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Profit")
+                    .value(Decimal::from_str("1000").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/balance-sheets")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/balance-sheets/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"BalanceSheet\",
+              \"currency\": \"USD\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"date\": \"2021-09-30T00:00:00Z\",
+              \"net_assets\": 1000,
+              \"assets\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Current Assets\",
+                  \"value\": 1500.5,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Cash\",
+                      \"value\": 1000,
+                      \"sub_items\": [],
+                      \"company\": null
+                    },
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Receivables\",
+                      \"value\": 500.5,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"liabilities\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Current Liabilities\",
+                  \"value\": 500.5,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Payables\",
+                      \"value\": 500.5,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"equity\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Retained Earnings\",
+                  \"value\": 1000,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Profit\",
+                      \"value\": 1000,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/balance-sheets\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = BalanceSheetModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("BalanceSheet")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .date("2021-09-30T00:00:00Z")
+            .net_assets(Decimal::from_str("1000").unwrap())
+            .assets(vec![ReportItemBuilder::default()
+                .name("Current Assets")
+                .value(Decimal::from_str("1500.5").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Cash")
+                        .value(Decimal::from_str("1000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Receivables")
+                        .value(Decimal::from_str("500.5").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .liabilities(vec![ReportItemBuilder::default()
+                .name("Current Liabilities")
+                .value(Decimal::from_str("500.5").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Payables")
+                    .value(Decimal::from_str("500.5").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .equity(vec![ReportItemBuilder::default()
+                .name("Retained Earnings")
+                .value(Decimal::from_str("1000").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Profit")
+                    .value(Decimal::from_str("1000").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/balance-sheets")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: BalanceSheetModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        assert_eq!(response.flatten().len(), 7);
+        assert!(response.rollup_mismatches().is_empty());
+        m.assert()
+    }
+}
@@ -0,0 +1,480 @@
+use crate::configuration::AccountingConfig;
+use crate::report::{section, Report, ReportItem};
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CashFlowStatementModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_period: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_period: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub cash_at_beginning_of_period: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub cash_at_end_of_period: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub operating_activities: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub investing_activities: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub financing_activities: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_generated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="cash-flow-statements", return_type=Paginated<CashFlowStatementModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<CashFlowStatementModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="cash-flow-statements", return_type=CashFlowStatementModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+impl Report for CashFlowStatementModel {
+    fn sections(&self) -> Vec<(&'static str, &[ReportItem])> {
+        vec![
+            ("operating_activities", section(&self.operating_activities)),
+            ("investing_activities", section(&self.investing_activities)),
+            ("financing_activities", section(&self.financing_activities)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::ReportItemBuilder;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/cash-flow-statements?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"CashFlowStatement\",
+                      \"currency\": \"USD\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"start_period\": \"2021-01-01T00:00:00Z\",
+                      \"end_period\": \"2021-12-31T00:00:00Z\",
+                      \"cash_at_beginning_of_period\": 4000,
+                      \"cash_at_end_of_period\": 5000,
+                      \"operating_activities\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Operating Activities\",
+                          \"value\": 3000,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Net Income\",
+                              \"value\": 3200,
+                              \"sub_items\": [],
+                              \"company\": null
+                            },
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Depreciation\",
+                              \"value\": -200,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"investing_activities\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Investing Activities\",
+                          \"value\": -1500,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Equipment\",
+                              \"value\": -1500,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"financing_activities\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Financing Activities\",
+                          \"value\": -500,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Loan Repayment\",
+                              \"value\": -500,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/cash-flow-statements\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CashFlowStatementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("CashFlowStatement")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .start_period("2021-01-01T00:00:00Z")
+            .end_period("2021-12-31T00:00:00Z")
+            .cash_at_beginning_of_period(Decimal::from_str("4000").unwrap())
+            .cash_at_end_of_period(Decimal::from_str("5000").unwrap())
+            .operating_activities(vec![ReportItemBuilder::default()
+                .name("Operating Activities")
+                .value(Decimal::from_str("3000").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Net Income")
+                        .value(Decimal::from_str("3200").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Depreciation")
+                        .value(Decimal::from_str("-200").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .investing_activities(vec![ReportItemBuilder::default()
+                .name("Investing Activities")
+                .value(Decimal::from_str("-1500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Equipment")
+                    .value(Decimal::from_str("-1500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .financing_activities(vec![ReportItemBuilder::default()
+                .name("Financing Activities")
+                .value(Decimal::from_str("-500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Loan Repayment")
+                    .value(Decimal::from_str("-500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/cash-flow-statements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/cash-flow-statements/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"CashFlowStatement\",
+              \"currency\": \"USD\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"start_period\": \"2021-01-01T00:00:00Z\",
+              \"end_period\": \"2021-12-31T00:00:00Z\",
+              \"cash_at_beginning_of_period\": 4000,
+              \"cash_at_end_of_period\": 5000,
+              \"operating_activities\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Operating Activities\",
+                  \"value\": 3000,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Net Income\",
+                      \"value\": 3200,
+                      \"sub_items\": [],
+                      \"company\": null
+                    },
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Depreciation\",
+                      \"value\": -200,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"investing_activities\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Investing Activities\",
+                  \"value\": -1500,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Equipment\",
+                      \"value\": -1500,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"financing_activities\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Financing Activities\",
+                  \"value\": -500,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Loan Repayment\",
+                      \"value\": -500,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/cash-flow-statements\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CashFlowStatementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("CashFlowStatement")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .start_period("2021-01-01T00:00:00Z")
+            .end_period("2021-12-31T00:00:00Z")
+            .cash_at_beginning_of_period(Decimal::from_str("4000").unwrap())
+            .cash_at_end_of_period(Decimal::from_str("5000").unwrap())
+            .operating_activities(vec![ReportItemBuilder::default()
+                .name("Operating Activities")
+                .value(Decimal::from_str("3000").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Net Income")
+                        .value(Decimal::from_str("3200").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Depreciation")
+                        .value(Decimal::from_str("-200").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .investing_activities(vec![ReportItemBuilder::default()
+                .name("Investing Activities")
+                .value(Decimal::from_str("-1500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Equipment")
+                    .value(Decimal::from_str("-1500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .financing_activities(vec![ReportItemBuilder::default()
+                .name("Financing Activities")
+                .value(Decimal::from_str("-500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Loan Repayment")
+                    .value(Decimal::from_str("-500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/cash-flow-statements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: CashFlowStatementModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        assert_eq!(response.flatten().len(), 7);
+        assert!(response.rollup_mismatches().is_empty());
+        m.assert()
+    }
+}
@@ -0,0 +1,426 @@
+use crate::configuration::AccountingConfig;
+use crate::models::company_info::CompanyInfoModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ContactModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_supplier: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_customer: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tax_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub addresses: Option<Vec<Address>>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_numbers: Option<Vec<AccountingPhoneNumber>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Address {
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub street_1: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub street_2: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub city: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub state: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub country_subdivision: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub country: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub zip_code: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AccountingPhoneNumber {
+    #[builder(setter(into, strip_option), default)]
+    pub number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="contacts", return_type=Paginated<ContactModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ContactModel` that `expand` can return in full.
+    pub enum ContactExpand {
+        Addresses => "addresses",
+        PhoneNumbers => "phone_numbers",
+        Company => "company",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ContactExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_customer: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_supplier: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<ContactModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="contacts", return_type=ContactModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ContactExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/contacts?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Gil Feig's pickleball store\",
+                      \"is_supplier\": true,
+                      \"is_customer\": false,
+                      \"email_address\": \"pam@dunder.com\",
+                      \"tax_number\": \"12-3456789\",
+                      \"status\": \"ACTIVE\",
+                      \"currency\": \"USD\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"addresses\": [
+                        {
+                          \"type\": \"BILLING\",
+                          \"street_1\": \"50 Bowling Green Dr\",
+                          \"street_2\": \"Golden Gate Park\",
+                          \"city\": \"San Francisco\",
+                          \"state\": \"California\",
+                          \"country_subdivision\": \"CA\",
+                          \"country\": \"US\",
+                          \"zip_code\": \"94122\"
+                        }
+                      ],
+                      \"phone_numbers\": [
+                        {
+                          \"number\": \"+3198675309\",
+                          \"type\": \"Mobile\"
+                        }
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/contacts\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ContactModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Gil Feig's pickleball store")
+            .is_supplier(true)
+            .is_customer(false)
+            .email_address("pam@dunder.com")
+            .tax_number("12-3456789")
+            .status("ACTIVE")
+            .currency("USD")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .addresses(vec![AddressBuilder::default()
+                .r#type("BILLING")
+                .street_1("50 Bowling Green Dr")
+                .street_2("Golden Gate Park")
+                .city("San Francisco")
+                .state("California")
+                .country_subdivision("CA")
+                .country("US")
+                .zip_code("94122")
+                .build()
+                .unwrap()])
+            .phone_numbers(vec![AccountingPhoneNumberBuilder::default()
+                .number("+3198675309")
+                .r#type("Mobile")
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/contacts")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/contacts/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Gil Feig's pickleball store\",
+              \"is_supplier\": true,
+              \"is_customer\": false,
+              \"email_address\": \"pam@dunder.com\",
+              \"tax_number\": \"12-3456789\",
+              \"status\": \"ACTIVE\",
+              \"currency\": \"USD\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"addresses\": [
+                {
+                  \"type\": \"BILLING\",
+                  \"street_1\": \"50 Bowling Green Dr\",
+                  \"street_2\": \"Golden Gate Park\",
+                  \"city\": \"San Francisco\",
+                  \"state\": \"California\",
+                  \"country_subdivision\": \"CA\",
+                  \"country\": \"US\",
+                  \"zip_code\": \"94122\"
+                }
+              ],
+              \"phone_numbers\": [
+                {
+                  \"number\": \"+3198675309\",
+                  \"type\": \"Mobile\"
+                }
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/contacts\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ContactModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Gil Feig's pickleball store")
+            .is_supplier(true)
+            .is_customer(false)
+            .email_address("pam@dunder.com")
+            .tax_number("12-3456789")
+            .status("ACTIVE")
+            .currency("USD")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .addresses(vec![AddressBuilder::default()
+                .r#type("BILLING")
+                .street_1("50 Bowling Green Dr")
+                .street_2("Golden Gate Park")
+                .city("San Francisco")
+                .state("California")
+                .country_subdivision("CA")
+                .country("US")
+                .zip_code("94122")
+                .build()
+                .unwrap()])
+            .phone_numbers(vec![AccountingPhoneNumberBuilder::default()
+                .number("+3198675309")
+                .r#type("Mobile")
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/contacts")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ContactModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_suppliers() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/contacts?is_supplier=true&name=Gil%20Feig%27s%20pickleball%20store",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .is_supplier(true)
+            .name("Gil Feig's pickleball store")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+}
@@ -0,0 +1,436 @@
+use crate::configuration::AccountingConfig;
+use crate::models::company_info::CompanyInfoModel;
+use crate::models::contacts::ContactModel;
+use crate::models::payments::PaymentModel;
+use crate::models::tracking_categories::TrackingCategoryModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CreditNoteModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<Expandable<ContactModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_amount: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub remaining_credit: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub line_items: Option<Vec<CreditNoteLineItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<Expandable<TrackingCategoryModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub payments: Option<Vec<Expandable<PaymentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CreditNoteLineItem {
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub item: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub quantity: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub memo: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub unit_price: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub tax_rate: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_line_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_category: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="credit-notes", return_type=Paginated<CreditNoteModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `CreditNoteModel` that `expand` can return in full.
+    pub enum CreditNoteExpand {
+        Contact => "contact",
+        Company => "company",
+        TrackingCategories => "tracking_categories",
+        Payments => "payments",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CreditNoteExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_before: Option<DateTime<Utc>>,
+}
+
+pub type GetRequestResponse = Paginated<CreditNoteModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="credit-notes", return_type=CreditNoteModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CreditNoteExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/credit-notes?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"transaction_date\": \"2021-10-01\",
+                      \"status\": \"PAID\",
+                      \"number\": \"AIQ12546\",
+                      \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"total_amount\": 260,
+                      \"remaining_credit\": 20,
+                      \"line_items\": [
+                        {
+                          \"remote_id\": \"19202938\",
+                          \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                          \"name\": \"Basic Monthly\",
+                          \"description\": \"Prorated Subscription\",
+                          \"quantity\": 1,
+                          \"memo\": \"Weekly Payment\",
+                          \"unit_price\": 5,
+                          \"tax_rate\": \"1ba8a02e-3f08-4276-9725-6b38ea8d70a4\",
+                          \"total_line_amount\": 5,
+                          \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                          \"tracking_categories\": [
+                            \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                          ],
+                          \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                          \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                        }
+                      ],
+                      \"tracking_categories\": [
+                        \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                      ],
+                      \"currency\": \"USD\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"payments\": [
+                        \"b26fd49a-cbae-470a-a8f8-bcbc119e0390\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/credit-notes\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CreditNoteModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_date("2021-10-01")
+            .status("PAID")
+            .number("AIQ12546")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .total_amount(Decimal::from_str("260").unwrap())
+            .remaining_credit(Decimal::from_str("20").unwrap())
+            .line_items(vec![CreditNoteLineItemBuilder::default()
+                .remote_id("19202938")
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .name("Basic Monthly")
+                .description("Prorated Subscription")
+                .quantity(Decimal::from_str("1").unwrap())
+                .memo("Weekly Payment")
+                .unit_price(Decimal::from_str("5").unwrap())
+                .tax_rate("1ba8a02e-3f08-4276-9725-6b38ea8d70a4")
+                .total_line_amount(Decimal::from_str("5").unwrap())
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .currency("USD")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .payments(vec![Expandable::from(
+                "b26fd49a-cbae-470a-a8f8-bcbc119e0390",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/credit-notes")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/credit-notes/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"transaction_date\": \"2021-10-01\",
+              \"status\": \"PAID\",
+              \"number\": \"AIQ12546\",
+              \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"total_amount\": 260,
+              \"remaining_credit\": 20,
+              \"line_items\": [
+                {
+                  \"remote_id\": \"19202938\",
+                  \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                  \"name\": \"Basic Monthly\",
+                  \"description\": \"Prorated Subscription\",
+                  \"quantity\": 1,
+                  \"memo\": \"Weekly Payment\",
+                  \"unit_price\": 5,
+                  \"tax_rate\": \"1ba8a02e-3f08-4276-9725-6b38ea8d70a4\",
+                  \"total_line_amount\": 5,
+                  \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                  \"tracking_categories\": [
+                    \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                  ],
+                  \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                  \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                }
+              ],
+              \"tracking_categories\": [
+                \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+              ],
+              \"currency\": \"USD\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"payments\": [
+                \"b26fd49a-cbae-470a-a8f8-bcbc119e0390\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/credit-notes\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CreditNoteModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_date("2021-10-01")
+            .status("PAID")
+            .number("AIQ12546")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .total_amount(Decimal::from_str("260").unwrap())
+            .remaining_credit(Decimal::from_str("20").unwrap())
+            .line_items(vec![CreditNoteLineItemBuilder::default()
+                .remote_id("19202938")
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .name("Basic Monthly")
+                .description("Prorated Subscription")
+                .quantity(Decimal::from_str("1").unwrap())
+                .memo("Weekly Payment")
+                .unit_price(Decimal::from_str("5").unwrap())
+                .tax_rate("1ba8a02e-3f08-4276-9725-6b38ea8d70a4")
+                .total_line_amount(Decimal::from_str("5").unwrap())
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .currency("USD")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .payments(vec![Expandable::from(
+                "b26fd49a-cbae-470a-a8f8-bcbc119e0390",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/credit-notes")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: CreditNoteModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,411 @@
+use crate::configuration::AccountingConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::company_info::CompanyInfoModel;
+use crate::models::contacts::ContactModel;
+use crate::models::tracking_categories::TrackingCategoryModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ExpenseModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<Expandable<ContactModel>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_amount: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub sub_total: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_tax_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub memo: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub lines: Option<Vec<ExpenseLine>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<Expandable<TrackingCategoryModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ExpenseLine {
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub item: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub net_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_category: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="expenses", return_type=Paginated<ExpenseModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ExpenseModel` that `expand` can return in full.
+    pub enum ExpenseExpand {
+        Account => "account",
+        Contact => "contact",
+        Company => "company",
+        TrackingCategories => "tracking_categories",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ExpenseExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_before: Option<DateTime<Utc>>,
+}
+
+pub type GetRequestResponse = Paginated<ExpenseModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="expenses", return_type=ExpenseModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ExpenseExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/expenses?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"transaction_date\": \"2021-10-01\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                      \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                      \"total_amount\": 10000,
+                      \"sub_total\": 9500,
+                      \"total_tax_amount\": 500,
+                      \"currency\": \"USD\",
+                      \"exchange_rate\": \"2.9\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"memo\": \"Weekly Payment\",
+                      \"lines\": [
+                        {
+                          \"remote_id\": \"19202938\",
+                          \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                          \"net_amount\": 25.54,
+                          \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                          \"tracking_categories\": [
+                            \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                          ],
+                          \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                          \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                          \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                          \"description\": \"Office supplies\",
+                          \"exchange_rate\": \"2.9\"
+                        }
+                      ],
+                      \"tracking_categories\": [
+                        \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/expenses\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ExpenseModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_date("2021-10-01")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .total_amount(Decimal::from_str("10000").unwrap())
+            .sub_total(Decimal::from_str("9500").unwrap())
+            .total_tax_amount(Decimal::from_str("500").unwrap())
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .memo("Weekly Payment")
+            .lines(vec![ExpenseLineBuilder::default()
+                .remote_id("19202938")
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .net_amount(Decimal::from_str("25.54").unwrap())
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+                .description("Office supplies")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/expenses")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/expenses/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"transaction_date\": \"2021-10-01\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+              \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+              \"total_amount\": 10000,
+              \"sub_total\": 9500,
+              \"total_tax_amount\": 500,
+              \"currency\": \"USD\",
+              \"exchange_rate\": \"2.9\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"memo\": \"Weekly Payment\",
+              \"lines\": [
+                {
+                  \"remote_id\": \"19202938\",
+                  \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                  \"net_amount\": 25.54,
+                  \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                  \"tracking_categories\": [
+                    \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                  ],
+                  \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                  \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                  \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                  \"description\": \"Office supplies\",
+                  \"exchange_rate\": \"2.9\"
+                }
+              ],
+              \"tracking_categories\": [
+                \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/expenses\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ExpenseModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_date("2021-10-01")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .total_amount(Decimal::from_str("10000").unwrap())
+            .sub_total(Decimal::from_str("9500").unwrap())
+            .total_tax_amount(Decimal::from_str("500").unwrap())
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .memo("Weekly Payment")
+            .lines(vec![ExpenseLineBuilder::default()
+                .remote_id("19202938")
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .net_amount(Decimal::from_str("25.54").unwrap())
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+                .description("Office supplies")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/expenses")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ExpenseModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,579 @@
+use crate::configuration::AccountingConfig;
+use crate::report::{section, Report, ReportItem};
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct IncomeStatementModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_period: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_period: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub income: Option<Vec<ReportItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cost_of_sales: Option<Vec<ReportItem>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub gross_profit: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub operating_expenses: Option<Vec<ReportItem>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub net_operating_income: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub non_operating_expenses: Option<Vec<ReportItem>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub net_income: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_generated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="income-statements", return_type=Paginated<IncomeStatementModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<IncomeStatementModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="income-statements", return_type=IncomeStatementModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+impl Report for IncomeStatementModel {
+    fn sections(&self) -> Vec<(&'static str, &[ReportItem])> {
+        vec![
+            ("income", section(&self.income)),
+            ("cost_of_sales", section(&self.cost_of_sales)),
+            ("operating_expenses", section(&self.operating_expenses)),
+            (
+                "non_operating_expenses",
+                section(&self.non_operating_expenses),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::ReportItemBuilder;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/income-statements?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"IncomeStatement\",
+                      \"currency\": \"USD\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"start_period\": \"2021-01-01T00:00:00Z\",
+                      \"end_period\": \"2021-12-31T00:00:00Z\",
+                      \"income\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Revenue\",
+                          \"value\": 10000,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Product Sales\",
+                              \"value\": 7000,
+                              \"sub_items\": [],
+                              \"company\": null
+                            },
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Services\",
+                              \"value\": 3000,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"cost_of_sales\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Cost of Goods Sold\",
+                          \"value\": 2500,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Materials\",
+                              \"value\": 2500,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"gross_profit\": 7500,
+                      \"operating_expenses\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Operating Expenses\",
+                          \"value\": 4200.25,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Rent\",
+                              \"value\": 1200.25,
+                              \"sub_items\": [],
+                              \"company\": null
+                            },
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Salaries\",
+                              \"value\": 3000,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"net_operating_income\": 3299.75,
+                      \"non_operating_expenses\": [
+                        {
+                          \"remote_id\": null,
+                          \"name\": \"Other Expenses\",
+                          \"value\": 99.75,
+                          \"sub_items\": [
+                            {
+                              \"remote_id\": null,
+                              \"name\": \"Interest\",
+                              \"value\": 99.75,
+                              \"sub_items\": [],
+                              \"company\": null
+                            }
+                          ],
+                          \"company\": null
+                        }
+                      ],
+                      \"net_income\": 3200,
+                      \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/income-statements\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = IncomeStatementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("IncomeStatement")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .start_period("2021-01-01T00:00:00Z")
+            .end_period("2021-12-31T00:00:00Z")
+            .income(vec![ReportItemBuilder::default()
+                .name("Revenue")
+                .value(Decimal::from_str("10000").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Product Sales")
+                        .value(Decimal::from_str("7000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Services")
+                        .value(Decimal::from_str("3000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .cost_of_sales(vec![ReportItemBuilder::default()
+                .name("Cost of Goods Sold")
+                .value(Decimal::from_str("2500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Materials")
+                    .value(Decimal::from_str("2500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .gross_profit(Decimal::from_str("7500").unwrap())
+            .operating_expenses(vec![ReportItemBuilder::default()
+                .name("Operating Expenses")
+                .value(Decimal::from_str("4200.25").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Rent")
+                        .value(Decimal::from_str("1200.25").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Salaries")
+                        .value(Decimal::from_str("3000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .net_operating_income(Decimal::from_str("3299.75").unwrap())
+            .non_operating_expenses(vec![ReportItemBuilder::default()
+                .name("Other Expenses")
+                .value(Decimal::from_str("99.75").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Interest")
+                    .value(Decimal::from_str("99.75").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .net_income(Decimal::from_str("3200").unwrap())
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/income-statements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/income-statements/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"IncomeStatement\",
+              \"currency\": \"USD\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"start_period\": \"2021-01-01T00:00:00Z\",
+              \"end_period\": \"2021-12-31T00:00:00Z\",
+              \"income\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Revenue\",
+                  \"value\": 10000,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Product Sales\",
+                      \"value\": 7000,
+                      \"sub_items\": [],
+                      \"company\": null
+                    },
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Services\",
+                      \"value\": 3000,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"cost_of_sales\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Cost of Goods Sold\",
+                  \"value\": 2500,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Materials\",
+                      \"value\": 2500,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"gross_profit\": 7500,
+              \"operating_expenses\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Operating Expenses\",
+                  \"value\": 4200.25,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Rent\",
+                      \"value\": 1200.25,
+                      \"sub_items\": [],
+                      \"company\": null
+                    },
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Salaries\",
+                      \"value\": 3000,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"net_operating_income\": 3299.75,
+              \"non_operating_expenses\": [
+                {
+                  \"remote_id\": null,
+                  \"name\": \"Other Expenses\",
+                  \"value\": 99.75,
+                  \"sub_items\": [
+                    {
+                      \"remote_id\": null,
+                      \"name\": \"Interest\",
+                      \"value\": 99.75,
+                      \"sub_items\": [],
+                      \"company\": null
+                    }
+                  ],
+                  \"company\": null
+                }
+              ],
+              \"net_income\": 3200,
+              \"remote_generated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/income-statements\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = IncomeStatementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("IncomeStatement")
+            .currency("USD")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .start_period("2021-01-01T00:00:00Z")
+            .end_period("2021-12-31T00:00:00Z")
+            .income(vec![ReportItemBuilder::default()
+                .name("Revenue")
+                .value(Decimal::from_str("10000").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Product Sales")
+                        .value(Decimal::from_str("7000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Services")
+                        .value(Decimal::from_str("3000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .cost_of_sales(vec![ReportItemBuilder::default()
+                .name("Cost of Goods Sold")
+                .value(Decimal::from_str("2500").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Materials")
+                    .value(Decimal::from_str("2500").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .gross_profit(Decimal::from_str("7500").unwrap())
+            .operating_expenses(vec![ReportItemBuilder::default()
+                .name("Operating Expenses")
+                .value(Decimal::from_str("4200.25").unwrap())
+                .sub_items(vec![
+                    ReportItemBuilder::default()
+                        .name("Rent")
+                        .value(Decimal::from_str("1200.25").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                    ReportItemBuilder::default()
+                        .name("Salaries")
+                        .value(Decimal::from_str("3000").unwrap())
+                        .sub_items(vec![])
+                        .build()
+                        .unwrap(),
+                ])
+                .build()
+                .unwrap()])
+            .net_operating_income(Decimal::from_str("3299.75").unwrap())
+            .non_operating_expenses(vec![ReportItemBuilder::default()
+                .name("Other Expenses")
+                .value(Decimal::from_str("99.75").unwrap())
+                .sub_items(vec![ReportItemBuilder::default()
+                    .name("Interest")
+                    .value(Decimal::from_str("99.75").unwrap())
+                    .sub_items(vec![])
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap()])
+            .net_income(Decimal::from_str("3200").unwrap())
+            .remote_generated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/income-statements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: IncomeStatementModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        assert_eq!(response.flatten().len(), 10);
+        assert!(response.rollup_mismatches().is_empty());
+        m.assert()
+    }
+}
@@ -0,0 +1,538 @@
+use crate::configuration::AccountingConfig;
+use crate::models::company_info::CompanyInfoModel;
+use crate::models::contacts::ContactModel;
+use crate::models::payments::PaymentModel;
+use crate::models::tracking_categories::TrackingCategoryModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct InvoiceModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<Expandable<ContactModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub issue_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub due_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub paid_on_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub memo: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_discount: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub sub_total: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_tax_amount: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_amount: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub balance: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub payments: Option<Vec<Expandable<PaymentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub line_items: Option<Vec<InvoiceLineItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<Expandable<TrackingCategoryModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct InvoiceLineItem {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub unit_price: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub quantity: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub item: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="invoices", return_type=Paginated<InvoiceModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `InvoiceModel` that `expand` can return in full.
+    pub enum InvoiceExpand {
+        Contact => "contact",
+        Company => "company",
+        Payments => "payments",
+        TrackingCategories => "tracking_categories",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<InvoiceExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub issue_date_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub issue_date_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<InvoiceModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="invoices", return_type=InvoiceModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<InvoiceExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/invoices?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"type\": \"ACCOUNTS_RECEIVABLE\",
+                      \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                      \"number\": \"AIQ12546\",
+                      \"issue_date\": \"2021-10-01\",
+                      \"due_date\": \"2021-10-01\",
+                      \"paid_on_date\": \"2021-10-01\",
+                      \"memo\": \"Weekly Payment\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"currency\": \"USD\",
+                      \"exchange_rate\": \"2.9\",
+                      \"total_discount\": 0,
+                      \"sub_total\": 100.0,
+                      \"total_tax_amount\": 5.99,
+                      \"total_amount\": 105.99,
+                      \"balance\": 105.99,
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"status\": \"PAID\",
+                      \"payments\": [
+                        \"b26fd49a-cbae-470a-a8f8-bcbc119e0390\"
+                      ],
+                      \"line_items\": [
+                        {
+                          \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                          \"remote_id\": \"19202938\",
+                          \"description\": \"Pickleball lessons\",
+                          \"unit_price\": 50.0,
+                          \"quantity\": 2,
+                          \"total_amount\": 100.0,
+                          \"currency\": \"USD\",
+                          \"exchange_rate\": \"2.9\",
+                          \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                          \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                          \"tracking_categories\": [
+                            \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                          ],
+                          \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                        }
+                      ],
+                      \"tracking_categories\": [
+                        \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/invoices\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = InvoiceModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .r#type("ACCOUNTS_RECEIVABLE")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .number("AIQ12546")
+            .issue_date("2021-10-01")
+            .due_date("2021-10-01")
+            .paid_on_date("2021-10-01")
+            .memo("Weekly Payment")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .total_discount(Decimal::from_str("0").unwrap())
+            .sub_total(Decimal::from_str("100.0").unwrap())
+            .total_tax_amount(Decimal::from_str("5.99").unwrap())
+            .total_amount(Decimal::from_str("105.99").unwrap())
+            .balance(Decimal::from_str("105.99").unwrap())
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .status("PAID")
+            .payments(vec![Expandable::from(
+                "b26fd49a-cbae-470a-a8f8-bcbc119e0390",
+            )])
+            .line_items(vec![InvoiceLineItemBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .remote_id("19202938")
+                .description("Pickleball lessons")
+                .unit_price(Decimal::from_str("50.0").unwrap())
+                .quantity(Decimal::from_str("2").unwrap())
+                .total_amount(Decimal::from_str("100.0").unwrap())
+                .currency("USD")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/invoices")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/invoices/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"type\": \"ACCOUNTS_RECEIVABLE\",
+              \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+              \"number\": \"AIQ12546\",
+              \"issue_date\": \"2021-10-01\",
+              \"due_date\": \"2021-10-01\",
+              \"paid_on_date\": \"2021-10-01\",
+              \"memo\": \"Weekly Payment\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"currency\": \"USD\",
+              \"exchange_rate\": \"2.9\",
+              \"total_discount\": 0,
+              \"sub_total\": 100.0,
+              \"total_tax_amount\": 5.99,
+              \"total_amount\": 105.99,
+              \"balance\": 105.99,
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"status\": \"PAID\",
+              \"payments\": [
+                \"b26fd49a-cbae-470a-a8f8-bcbc119e0390\"
+              ],
+              \"line_items\": [
+                {
+                  \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                  \"remote_id\": \"19202938\",
+                  \"description\": \"Pickleball lessons\",
+                  \"unit_price\": 50.0,
+                  \"quantity\": 2,
+                  \"total_amount\": 100.0,
+                  \"currency\": \"USD\",
+                  \"exchange_rate\": \"2.9\",
+                  \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                  \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                  \"tracking_categories\": [
+                    \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                  ],
+                  \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                }
+              ],
+              \"tracking_categories\": [
+                \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/invoices\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = InvoiceModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .r#type("ACCOUNTS_RECEIVABLE")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .number("AIQ12546")
+            .issue_date("2021-10-01")
+            .due_date("2021-10-01")
+            .paid_on_date("2021-10-01")
+            .memo("Weekly Payment")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .total_discount(Decimal::from_str("0").unwrap())
+            .sub_total(Decimal::from_str("100.0").unwrap())
+            .total_tax_amount(Decimal::from_str("5.99").unwrap())
+            .total_amount(Decimal::from_str("105.99").unwrap())
+            .balance(Decimal::from_str("105.99").unwrap())
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .status("PAID")
+            .payments(vec![Expandable::from(
+                "b26fd49a-cbae-470a-a8f8-bcbc119e0390",
+            )])
+            .line_items(vec![InvoiceLineItemBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .remote_id("19202938")
+                .description("Pickleball lessons")
+                .unit_price(Decimal::from_str("50.0").unwrap())
+                .quantity(Decimal::from_str("2").unwrap())
+                .total_amount(Decimal::from_str("100.0").unwrap())
+                .currency("USD")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/invoices")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: InvoiceModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_invoices() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/invoices?contact_id=022a2bef&issue_date_after=2020-03-31T00%3A00%3A00Z&type=ACCOUNTS_PAYABLE",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .contact_id("022a2bef")
+            .issue_date_after(Utc.with_ymd_and_hms(2020, 3, 31, 0, 0, 0).unwrap())
+            .r#type("ACCOUNTS_PAYABLE")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[test]
+    fn it_keeps_amounts_exact() {
+        let invoice: InvoiceModel = serde_json::from_str(
+            "{
+                \"id\": \"dbc5caf6-1d6b-42d1-afa6-b8e1c4a4ae39\",
+                \"sub_total\": 0.1,
+                \"total_tax_amount\": 0.2,
+                \"total_amount\": \"12345678901234.57\",
+                \"remote_was_deleted\": false
+            }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            invoice.sub_total.unwrap() + invoice.total_tax_amount.unwrap(),
+            Decimal::from_str("0.3").unwrap()
+        );
+        assert_eq!(
+            invoice.total_amount.unwrap().to_string(),
+            "12345678901234.57"
+        );
+    }
+}
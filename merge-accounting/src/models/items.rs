@@ -0,0 +1,280 @@
+use crate::configuration::AccountingConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::company_info::CompanyInfoModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ItemModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub unit_price: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub purchase_price: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub purchase_account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub sales_account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="items", return_type=Paginated<ItemModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ItemModel` that `expand` can return in full.
+    pub enum ItemExpand {
+        PurchaseAccount => "purchase_account",
+        SalesAccount => "sales_account",
+        Company => "company",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ItemExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<ItemModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="items", return_type=ItemModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ItemExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/accounting/v1/items?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Pickleball Paddle\",
+                      \"status\": \"ACTIVE\",
+                      \"unit_price\": 10,
+                      \"purchase_price\": 10,
+                      \"purchase_account\": \"659c6334-fbd4-4d2b-950a-f743b8816b96\",
+                      \"sales_account\": \"62bdb1e3-9252-4722-9fc7-e01ecdc6f10e\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/items\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = ItemModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Pickleball Paddle")
+            .status("ACTIVE")
+            .unit_price(Decimal::from_str("10").unwrap())
+            .purchase_price(Decimal::from_str("10").unwrap())
+            .purchase_account("659c6334-fbd4-4d2b-950a-f743b8816b96")
+            .sales_account("62bdb1e3-9252-4722-9fc7-e01ecdc6f10e")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/items")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/items/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Pickleball Paddle\",
+              \"status\": \"ACTIVE\",
+              \"unit_price\": 10,
+              \"purchase_price\": 10,
+              \"purchase_account\": \"659c6334-fbd4-4d2b-950a-f743b8816b96\",
+              \"sales_account\": \"62bdb1e3-9252-4722-9fc7-e01ecdc6f10e\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/items\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ItemModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Pickleball Paddle")
+            .status("ACTIVE")
+            .unit_price(Decimal::from_str("10").unwrap())
+            .purchase_price(Decimal::from_str("10").unwrap())
+            .purchase_account("659c6334-fbd4-4d2b-950a-f743b8816b96")
+            .sales_account("62bdb1e3-9252-4722-9fc7-e01ecdc6f10e")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/items")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ItemModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
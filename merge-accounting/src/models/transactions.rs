@@ -0,0 +1,469 @@
+use crate::configuration::AccountingConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::company_info::CompanyInfoModel;
+use crate::models::contacts::ContactModel;
+use crate::models::tracking_categories::TrackingCategoryModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TransactionModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<Expandable<ContactModel>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyInfoModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<Expandable<TrackingCategoryModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub line_items: Option<Vec<TransactionLineItem>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TransactionLineItem {
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub memo: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub unit_price: Option<Decimal>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub quantity: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub item: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_category: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tracking_categories: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub total_line_amount: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub tax_rate: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub currency: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub exchange_rate: Option<Decimal>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="transactions", return_type=Paginated<TransactionModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `TransactionModel` that `expand` can return in full.
+    pub enum TransactionExpand {
+        Account => "account",
+        Contact => "contact",
+        Company => "company",
+        TrackingCategories => "tracking_categories",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TransactionExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub transaction_date_before: Option<DateTime<Utc>>,
+}
+
+pub type GetRequestResponse = Paginated<TransactionModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="accounting", model="transactions", return_type=TransactionModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<AccountingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TransactionExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/transactions?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"transaction_type\": \"estimate\",
+                      \"number\": \"AIQ12546\",
+                      \"transaction_date\": \"2021-10-01\",
+                      \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                      \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                      \"total_amount\": 25.54,
+                      \"currency\": \"USD\",
+                      \"exchange_rate\": \"2.9\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"tracking_categories\": [
+                        \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                      ],
+                      \"line_items\": [
+                        {
+                          \"remote_id\": \"19202938\",
+                          \"memo\": \"Weekly Payment\",
+                          \"unit_price\": 5.99,
+                          \"quantity\": 1,
+                          \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                          \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                          \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                          \"tracking_categories\": [
+                            \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                          ],
+                          \"total_line_amount\": 5.99,
+                          \"tax_rate\": \"1ba8a02e-3f08-4276-9725-6b38ea8d70a4\",
+                          \"currency\": \"USD\",
+                          \"exchange_rate\": \"2.9\",
+                          \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                        }
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/transactions\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = TransactionModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_type("estimate")
+            .number("AIQ12546")
+            .transaction_date("2021-10-01")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .total_amount(Decimal::from_str("25.54").unwrap())
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .line_items(vec![TransactionLineItemBuilder::default()
+                .remote_id("19202938")
+                .memo("Weekly Payment")
+                .unit_price(Decimal::from_str("5.99").unwrap())
+                .quantity(Decimal::from_str("1").unwrap())
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .total_line_amount(Decimal::from_str("5.99").unwrap())
+                .tax_rate("1ba8a02e-3f08-4276-9725-6b38ea8d70a4")
+                .currency("USD")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/transactions")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[test]
+    fn it_keeps_the_precision_of_amounts() {
+        let transaction: TransactionModel = serde_json::from_str(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"account\": {
+                \"id\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                \"current_balance\": 1000.5,
+                \"remote_was_deleted\": false
+              },
+              \"company\": {
+                \"id\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                \"fiscal_year_end_month\": 12,
+                \"remote_was_deleted\": false
+              },
+              \"total_amount\": 1234567890123.4567891,
+              \"line_items\": [
+                {\"quantity\": 0.1000000000000000055, \"unit_price\": 12345678901234567.89}
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            transaction.total_amount,
+            Some(Decimal::from_str("1234567890123.4567891").unwrap())
+        );
+        let line_item = &transaction.line_items.as_ref().unwrap()[0];
+        assert_eq!(
+            line_item.quantity,
+            Some(Decimal::from_str("0.1000000000000000055").unwrap())
+        );
+        assert_eq!(
+            line_item.unit_price,
+            Some(Decimal::from_str("12345678901234567.89").unwrap())
+        );
+        let account = transaction
+            .account
+            .as_ref()
+            .and_then(Expandable::expanded)
+            .unwrap();
+        // Expanded models are buffered by the untagged `Expandable`, which reads numbers as f64.
+        assert_eq!(
+            account.current_balance,
+            Some(Decimal::from_str("1000.5").unwrap())
+        );
+        let company = transaction
+            .company
+            .as_ref()
+            .and_then(Expandable::expanded)
+            .unwrap();
+        assert_eq!(company.fiscal_year_end_month, Some(12));
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/accounting/v1/transactions/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"transaction_type\": \"estimate\",
+              \"number\": \"AIQ12546\",
+              \"transaction_date\": \"2021-10-01\",
+              \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+              \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+              \"total_amount\": 25.54,
+              \"currency\": \"USD\",
+              \"exchange_rate\": \"2.9\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"tracking_categories\": [
+                \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+              ],
+              \"line_items\": [
+                {
+                  \"remote_id\": \"19202938\",
+                  \"memo\": \"Weekly Payment\",
+                  \"unit_price\": 5.99,
+                  \"quantity\": 1,
+                  \"item\": \"447b7147-e84b-4512-908d-cc0995d67ebc\",
+                  \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                  \"tracking_category\": \"810be7cc-bc71-479c-94b8-1a26326a5aab\",
+                  \"tracking_categories\": [
+                    \"b38c59b0-a9d7-4740-b1ee-5436c6751e3d\"
+                  ],
+                  \"total_line_amount\": 5.99,
+                  \"tax_rate\": \"1ba8a02e-3f08-4276-9725-6b38ea8d70a4\",
+                  \"currency\": \"USD\",
+                  \"exchange_rate\": \"2.9\",
+                  \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\"
+                }
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/transactions\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = TransactionModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .transaction_type("estimate")
+            .number("AIQ12546")
+            .transaction_date("2021-10-01")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .total_amount(Decimal::from_str("25.54").unwrap())
+            .currency("USD")
+            .exchange_rate(Decimal::from_str("2.9").unwrap())
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .tracking_categories(vec![Expandable::from(
+                "b38c59b0-a9d7-4740-b1ee-5436c6751e3d",
+            )])
+            .line_items(vec![TransactionLineItemBuilder::default()
+                .remote_id("19202938")
+                .memo("Weekly Payment")
+                .unit_price(Decimal::from_str("5.99").unwrap())
+                .quantity(Decimal::from_str("1").unwrap())
+                .item("447b7147-e84b-4512-908d-cc0995d67ebc")
+                .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+                .tracking_category("810be7cc-bc71-479c-94b8-1a26326a5aab")
+                .tracking_categories(vec!["b38c59b0-a9d7-4740-b1ee-5436c6751e3d".to_string()])
+                .total_line_amount(Decimal::from_str("5.99").unwrap())
+                .tax_rate("1ba8a02e-3f08-4276-9725-6b38ea8d70a4")
+                .currency("USD")
+                .exchange_rate(Decimal::from_str("2.9").unwrap())
+                .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/transactions")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = AccountingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: TransactionModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,252 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// A line of a financial report. Totals carry the lines they are made of in `sub_items`, so a
+/// report section is a tree of `ReportItem`s.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ReportItem {
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "crate::amount::deserialize")]
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<Decimal>,
+    #[serde(default)]
+    #[builder(default)]
+    pub sub_items: Vec<ReportItem>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+}
+
+/// A `ReportItem` without its children, as produced by `Report::flatten`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportRow {
+    /// The section name followed by the name of every item down to this one, e.g.
+    /// `["assets", "Current Assets", "Cash"]`.
+    pub path: Vec<String>,
+    pub remote_id: Option<String>,
+    pub value: Option<Decimal>,
+}
+
+impl ReportRow {
+    /// How far below its section the row sits, `0` for top level items.
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(2)
+    }
+}
+
+/// An item whose value is not the sum of its children's values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollupMismatch {
+    pub path: Vec<String>,
+    pub value: Decimal,
+    pub children_total: Decimal,
+}
+
+impl RollupMismatch {
+    pub fn difference(&self) -> Decimal {
+        self.value - self.children_total
+    }
+}
+
+impl ReportItem {
+    fn path(&self, parent: &[String]) -> Vec<String> {
+        let mut path = parent.to_vec();
+        path.push(self.name.clone().unwrap_or_default());
+        path
+    }
+
+    fn flatten_into(&self, parent: &[String], rows: &mut Vec<ReportRow>) {
+        let path = self.path(parent);
+        rows.push(ReportRow {
+            path: path.clone(),
+            remote_id: self.remote_id.clone(),
+            value: self.value,
+        });
+        for item in &self.sub_items {
+            item.flatten_into(&path, rows);
+        }
+    }
+
+    /// The item's value, or for a group without one the total of its own sub-items. Leaves
+    /// without a value count as zero.
+    fn total(&self) -> Decimal {
+        self.value
+            .unwrap_or_else(|| self.sub_items.iter().map(ReportItem::total).sum())
+    }
+
+    /// Children without a value count as the total of their own sub-items. Leaves and items
+    /// without a value of their own are never reported.
+    fn check_rollups(&self, parent: &[String], mismatches: &mut Vec<RollupMismatch>) {
+        let path = self.path(parent);
+        if let Some(value) = self.value.filter(|_| !self.sub_items.is_empty()) {
+            let children_total = self
+                .sub_items
+                .iter()
+                .map(ReportItem::total)
+                .sum::<Decimal>();
+            if value != children_total {
+                mismatches.push(RollupMismatch {
+                    path: path.clone(),
+                    value,
+                    children_total,
+                });
+            }
+        }
+        for item in &self.sub_items {
+            item.check_rollups(&path, mismatches);
+        }
+    }
+}
+
+/// Helpers shared by the balance sheet, income statement and cash flow statement models.
+pub trait Report {
+    /// Every report-item tree of the report, keyed by the name of the field holding it.
+    fn sections(&self) -> Vec<(&'static str, &[ReportItem])>;
+
+    /// Every item of every section in depth-first order, parents before their children.
+    fn flatten(&self) -> Vec<ReportRow> {
+        let mut rows = vec![];
+        for (section, items) in self.sections() {
+            let parent = [section.to_string()];
+            for item in items {
+                item.flatten_into(&parent, &mut rows);
+            }
+        }
+        rows
+    }
+
+    /// Every item whose value differs from the sum of its children, so integrations with broken
+    /// rollups can be flagged.
+    fn rollup_mismatches(&self) -> Vec<RollupMismatch> {
+        let mut mismatches = vec![];
+        for (section, items) in self.sections() {
+            let parent = [section.to_string()];
+            for item in items {
+                item.check_rollups(&parent, &mut mismatches);
+            }
+        }
+        mismatches
+    }
+}
+
+/// Turns an optional section into a slice, empty when Merge left it out.
+pub(crate) fn section(items: &Option<Vec<ReportItem>>) -> &[ReportItem] {
+    items.as_deref().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    struct Statement {
+        assets: Option<Vec<ReportItem>>,
+        liabilities: Option<Vec<ReportItem>>,
+    }
+
+    impl Report for Statement {
+        fn sections(&self) -> Vec<(&'static str, &[ReportItem])> {
+            vec![
+                ("assets", section(&self.assets)),
+                ("liabilities", section(&self.liabilities)),
+            ]
+        }
+    }
+
+    fn item(name: &str, value: &str, sub_items: Vec<ReportItem>) -> ReportItem {
+        ReportItemBuilder::default()
+            .name(name)
+            .value(Decimal::from_str(value).unwrap())
+            .sub_items(sub_items)
+            .build()
+            .unwrap()
+    }
+
+    fn statement(cash: &str) -> Statement {
+        Statement {
+            assets: Some(vec![item(
+                "Current Assets",
+                "1500.00",
+                vec![
+                    item("Cash", cash, vec![]),
+                    item(
+                        "Receivables",
+                        "500",
+                        vec![
+                            item("Customer A", "200", vec![]),
+                            item("Customer B", "300", vec![]),
+                        ],
+                    ),
+                ],
+            )]),
+            liabilities: None,
+        }
+    }
+
+    #[test]
+    fn it_flattens_a_report_keeping_the_path() {
+        let rows = statement("1000").flatten();
+
+        let paths: Vec<String> = rows.iter().map(|row| row.path.join(" / ")).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "assets / Current Assets",
+                "assets / Current Assets / Cash",
+                "assets / Current Assets / Receivables",
+                "assets / Current Assets / Receivables / Customer A",
+                "assets / Current Assets / Receivables / Customer B",
+            ]
+        );
+        assert_eq!(rows[0].depth(), 0);
+        assert_eq!(rows[3].depth(), 2);
+        assert_eq!(rows[1].value, Some(Decimal::from_str("1000").unwrap()));
+    }
+
+    #[test]
+    fn it_accepts_consistent_rollups() {
+        assert!(statement("1000").rollup_mismatches().is_empty());
+    }
+
+    #[test]
+    fn it_totals_groups_without_a_value() {
+        let mut receivables = item(
+            "Receivables",
+            "0",
+            vec![
+                item("Customer A", "200", vec![]),
+                item("Customer B", "300", vec![]),
+            ],
+        );
+        receivables.value = None;
+        let statement = Statement {
+            assets: Some(vec![item(
+                "Current Assets",
+                "1500",
+                vec![item("Cash", "1000", vec![]), receivables],
+            )]),
+            liabilities: None,
+        };
+
+        assert!(statement.rollup_mismatches().is_empty());
+    }
+
+    #[test]
+    fn it_reports_broken_rollups() {
+        let mismatches = statement("999.99").rollup_mismatches();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, vec!["assets", "Current Assets"]);
+        assert_eq!(
+            mismatches[0].children_total,
+            Decimal::from_str("1499.99").unwrap()
+        );
+        assert_eq!(
+            mismatches[0].difference(),
+            Decimal::from_str("0.01").unwrap()
+        );
+    }
+}
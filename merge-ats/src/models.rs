@@ -0,0 +1,16 @@
+pub mod activities;
+pub mod applications;
+pub mod attachments;
+pub mod candidates;
+pub mod departments;
+pub mod eeocs;
+pub mod interviews;
+pub mod job_interview_stages;
+pub mod jobs;
+pub mod offers;
+pub mod offices;
+pub mod reject_reasons;
+pub mod remote_users;
+pub mod scorecards;
+pub mod screening_questions;
+pub mod tags;
@@ -0,0 +1,434 @@
+use crate::configuration::ATSConfig;
+use crate::models::remote_users::RemoteUserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ActivityModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub user: Option<Expandable<RemoteUserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub activity_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub subject: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub body: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub visibility: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="activities", return_type=Paginated<ActivityModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ActivityModel` that `expand` can return in full.
+    pub enum ActivityExpand {
+        User => "user",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ActivityExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub user_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<ActivityModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="activities", return_type=ActivityModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ActivityExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+/// A note or email to log against a candidate. Which fields a linked account's integration
+/// actually needs can be discovered with `MetaPostRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ActivityRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub activity_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub candidate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="activities", return_type=WriteResponse<ActivityModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub model: ActivityRequest,
+    /// The `RemoteUserModel` the write is made on behalf of, required by Merge for every ATS write.
+    pub remote_user_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<ActivityModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="activities/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/activities?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"user\": \"ee11cbb1-9052-440b-97aa-c0ca060c23ee\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"activity_type\": \"NOTE\",
+                      \"subject\": \"Gil Feig's interview\",
+                      \"body\": \"Candidate showed strong communication skills.\",
+                      \"visibility\": \"PUBLIC\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/activities\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = ActivityModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .user("ee11cbb1-9052-440b-97aa-c0ca060c23ee")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .activity_type("NOTE")
+            .subject("Gil Feig's interview")
+            .body("Candidate showed strong communication skills.")
+            .visibility("PUBLIC")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/activities")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/activities/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"user\": \"ee11cbb1-9052-440b-97aa-c0ca060c23ee\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"activity_type\": \"NOTE\",
+              \"subject\": \"Gil Feig's interview\",
+              \"body\": \"Candidate showed strong communication skills.\",
+              \"visibility\": \"PUBLIC\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/activities\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ActivityModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .user("ee11cbb1-9052-440b-97aa-c0ca060c23ee")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .activity_type("NOTE")
+            .subject("Gil Feig's interview")
+            .body("Candidate showed strong communication skills.")
+            .visibility("PUBLIC")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/activities")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ActivityModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_note() {
+        let m = mock("POST", "/api/ats/v1/activities")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "activity_type": "NOTE",
+                    "subject": "Gil Feig's interview",
+                    "body": "Candidate showed strong communication skills.",
+                    "visibility": "PRIVATE",
+                    "candidate": "521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4"
+                },
+                "remote_user_id": "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee"
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"198123da-5b36-4e67-a4fd-7e2ff3e1aaf1\",
+                    \"activity_type\": \"NOTE\",
+                    \"subject\": \"Gil Feig's interview\",
+                    \"visibility\": \"PRIVATE\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let note: ActivityRequest = ActivityRequestBuilder::default()
+            .activity_type("NOTE")
+            .subject("Gil Feig's interview")
+            .body("Candidate showed strong communication skills.")
+            .visibility("PRIVATE")
+            .candidate("521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4")
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(note)
+            .remote_user_id("bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "198123da-5b36-4e67-a4fd-7e2ff3e1aaf1");
+        assert_eq!(response.model.visibility, Some("PRIVATE".to_string()));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/ats/v1/activities/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"first_name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": true,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "first_name"
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(response.has_conditional_params);
+        m.assert()
+    }
+}
@@ -0,0 +1,506 @@
+use crate::configuration::ATSConfig;
+use crate::models::candidates::CandidateModel;
+use crate::models::job_interview_stages::JobInterviewStageModel;
+use crate::models::jobs::JobModel;
+use crate::models::reject_reasons::RejectReasonModel;
+use crate::models::remote_users::RemoteUserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ApplicationModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub candidate: Option<Expandable<CandidateModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub job: Option<Expandable<JobModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub applied_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub rejected_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub source: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub credited_to: Option<Expandable<RemoteUserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub current_stage: Option<Expandable<JobInterviewStageModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub reject_reason: Option<Expandable<RejectReasonModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="applications", return_type=Paginated<ApplicationModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ApplicationModel` that `expand` can return in full.
+    pub enum ApplicationExpand {
+        Candidate => "candidate",
+        Job => "job",
+        CreditedTo => "credited_to",
+        CurrentStage => "current_stage",
+        RejectReason => "reject_reason",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub candidate_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub credited_to_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub current_stage_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ApplicationExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub job_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub reject_reason_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub source: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<ApplicationModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="applications", return_type=ApplicationModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ApplicationExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+/// The fields accepted when creating an application. Which of them a linked account's integration
+/// actually needs can be discovered with `MetaPostRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ApplicationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub candidate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub job: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub applied_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub rejected_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub credited_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub current_stage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub reject_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub remote_template_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="applications", return_type=WriteResponse<ApplicationModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub model: ApplicationRequest,
+    /// The `RemoteUserModel` the write is made on behalf of, required by Merge for every ATS write.
+    pub remote_user_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<ApplicationModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="applications/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/applications?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"candidate\": \"2021-10-01\",
+                      \"job\": \"9dddd5ce-1b13-45bc-997f-eeb871842d4b\",
+                      \"applied_at\": \"2021-12-06T10:11:26Z\",
+                      \"rejected_at\": \"2021-12-06T10:11:26Z\",
+                      \"source\": \"Campus recruiting event\",
+                      \"credited_to\": \"878012f5-1866-4b07-910f-a840a805f99d\",
+                      \"current_stage\": \"158afdd6-32d0-4cb6-947e-3fdc01536b95\",
+                      \"reject_reason\": \"b6118a49-3b0e-40de-9e13-21724c9bf126\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/applications\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = ApplicationModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .candidate("2021-10-01")
+            .job("9dddd5ce-1b13-45bc-997f-eeb871842d4b")
+            .applied_at("2021-12-06T10:11:26Z")
+            .rejected_at("2021-12-06T10:11:26Z")
+            .source("Campus recruiting event")
+            .credited_to("878012f5-1866-4b07-910f-a840a805f99d")
+            .current_stage("158afdd6-32d0-4cb6-947e-3fdc01536b95")
+            .reject_reason("b6118a49-3b0e-40de-9e13-21724c9bf126")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/applications")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/applications/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"candidate\": \"2021-10-01\",
+              \"job\": \"9dddd5ce-1b13-45bc-997f-eeb871842d4b\",
+              \"applied_at\": \"2021-12-06T10:11:26Z\",
+              \"rejected_at\": \"2021-12-06T10:11:26Z\",
+              \"source\": \"Campus recruiting event\",
+              \"credited_to\": \"878012f5-1866-4b07-910f-a840a805f99d\",
+              \"current_stage\": \"158afdd6-32d0-4cb6-947e-3fdc01536b95\",
+              \"reject_reason\": \"b6118a49-3b0e-40de-9e13-21724c9bf126\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/applications\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ApplicationModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .candidate("2021-10-01")
+            .job("9dddd5ce-1b13-45bc-997f-eeb871842d4b")
+            .applied_at("2021-12-06T10:11:26Z")
+            .rejected_at("2021-12-06T10:11:26Z")
+            .source("Campus recruiting event")
+            .credited_to("878012f5-1866-4b07-910f-a840a805f99d")
+            .current_stage("158afdd6-32d0-4cb6-947e-3fdc01536b95")
+            .reject_reason("b6118a49-3b0e-40de-9e13-21724c9bf126")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/applications")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ApplicationModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_by_job() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/applications?job_id=52bf9b5e&source=Campus%20recruiting%20event",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .job_id("52bf9b5e")
+            .source("Campus recruiting event")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_an_application() {
+        let m = mock("POST", "/api/ats/v1/applications?run_async=true")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "candidate": "521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4",
+                    "job": "52bf9b5e-0beb-4f6f-8a72-cd4dca7ca633",
+                    "source": "Campus recruiting event"
+                },
+                "remote_user_id": "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee"
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"92e8a369-fffe-430d-b93a-f7e8a16563f1\",
+                    \"candidate\": \"521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4\",
+                    \"job\": \"52bf9b5e-0beb-4f6f-8a72-cd4dca7ca633\",
+                    \"source\": \"Campus recruiting event\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let application: ApplicationRequest = ApplicationRequestBuilder::default()
+            .candidate("521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4")
+            .job("52bf9b5e-0beb-4f6f-8a72-cd4dca7ca633")
+            .source("Campus recruiting event")
+            .build()
+            .unwrap();
+
+        let params: CreateRequestParams = CreateRequestParamsBuilder::default()
+            .run_async(true)
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(application)
+            .remote_user_id("bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "92e8a369-fffe-430d-b93a-f7e8a16563f1");
+        assert_eq!(
+            response.model.job.as_ref().and_then(Expandable::id),
+            Some("52bf9b5e-0beb-4f6f-8a72-cd4dca7ca633")
+        );
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/ats/v1/applications/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"first_name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": true,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "first_name"
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(response.has_conditional_params);
+        m.assert()
+    }
+}
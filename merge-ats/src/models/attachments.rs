@@ -0,0 +1,415 @@
+use crate::configuration::ATSConfig;
+use crate::models::candidates::CandidateModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AttachmentModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub candidate: Option<Expandable<CandidateModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub attachment_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="attachments", return_type=Paginated<AttachmentModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `AttachmentModel` that `expand` can return in full.
+    pub enum AttachmentExpand {
+        Candidate => "candidate",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub candidate_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AttachmentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<AttachmentModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="attachments", return_type=AttachmentModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AttachmentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+/// An attachment to upload for a candidate, given as a url the third party can download. Which
+/// fields a linked account's integration actually needs can be discovered with `MetaPostRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AttachmentRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub file_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub candidate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub attachment_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="attachments", return_type=WriteResponse<AttachmentModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub model: AttachmentRequest,
+    /// The `RemoteUserModel` the write is made on behalf of, required by Merge for every ATS write.
+    pub remote_user_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<AttachmentModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="attachments/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/attachments?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"file_name\": \"Candidate Resume\",
+                      \"file_url\": \"http://alturl.com/p749b\",
+                      \"candidate\": \"2021-10-01\",
+                      \"attachment_type\": \"RESUME\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/attachments\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = AttachmentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .file_name("Candidate Resume")
+            .file_url("http://alturl.com/p749b")
+            .candidate("2021-10-01")
+            .attachment_type("RESUME")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/attachments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/attachments/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"file_name\": \"Candidate Resume\",
+              \"file_url\": \"http://alturl.com/p749b\",
+              \"candidate\": \"2021-10-01\",
+              \"attachment_type\": \"RESUME\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/attachments\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AttachmentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .file_name("Candidate Resume")
+            .file_url("http://alturl.com/p749b")
+            .candidate("2021-10-01")
+            .attachment_type("RESUME")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/attachments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: AttachmentModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_an_attachment() {
+        let m = mock("POST", "/api/ats/v1/attachments")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "file_name": "Candidate Resume",
+                    "file_url": "http://alturl.com/p749b",
+                    "candidate": "521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4",
+                    "attachment_type": "RESUME"
+                },
+                "remote_user_id": "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee"
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"557a2a5c-1ddf-45f1-9bfd-f6d2ed9c1e5b\",
+                    \"file_name\": \"Candidate Resume\",
+                    \"file_url\": \"http://alturl.com/p749b\",
+                    \"candidate\": \"521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4\",
+                    \"attachment_type\": \"RESUME\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let attachment: AttachmentRequest = AttachmentRequestBuilder::default()
+            .file_name("Candidate Resume")
+            .file_url("http://alturl.com/p749b")
+            .candidate("521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4")
+            .attachment_type("RESUME")
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(attachment)
+            .remote_user_id("bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "557a2a5c-1ddf-45f1-9bfd-f6d2ed9c1e5b");
+        assert_eq!(response.model.attachment_type, Some("RESUME".to_string()));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/ats/v1/attachments/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"first_name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": true,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "first_name"
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(response.has_conditional_params);
+        m.assert()
+    }
+}
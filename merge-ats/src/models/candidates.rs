@@ -0,0 +1,848 @@
+use crate::configuration::ATSConfig;
+use crate::models::applications::ApplicationModel;
+use crate::models::attachments::AttachmentModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CandidateModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_interaction_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_private: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub can_email: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub locations: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_numbers: Option<Vec<PhoneNumber>>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_addresses: Option<Vec<EmailAddress>>,
+    #[builder(setter(into, strip_option), default)]
+    pub urls: Option<Vec<Url>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub applications: Option<Vec<Expandable<ApplicationModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub attachments: Option<Vec<Expandable<AttachmentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct PhoneNumber {
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_number_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EmailAddress {
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_address_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Url {
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub url_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates", return_type=Paginated<CandidateModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `CandidateModel` that `expand` can return in full.
+    pub enum CandidateExpand {
+        Applications => "applications",
+        Attachments => "attachments",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_addresses: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CandidateExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<CandidateModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates", return_type=CandidateModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CandidateExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+/// The fields accepted when creating or updating a candidate. Which of them a linked account's
+/// integration actually needs can be discovered with `MetaPostRequest` and `MetaPatchRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CandidateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub last_interaction_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub is_private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub can_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub locations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub phone_numbers: Option<Vec<PhoneNumber>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub email_addresses: Option<Vec<EmailAddress>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub urls: Option<Vec<Url>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub applications: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub attachments: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub remote_template_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates", return_type=WriteResponse<CandidateModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub model: CandidateRequest,
+    /// The `RemoteUserModel` the write is made on behalf of, required by Merge for every ATS write.
+    pub remote_user_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<CandidateModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates", return_type=WriteResponse<CandidateModel>, method="PATCH")]
+pub struct UpdateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    pub model: CandidateRequest,
+    /// The `RemoteUserModel` the write is made on behalf of, required by Merge for every ATS write.
+    pub remote_user_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<UpdateRequestParams>,
+}
+
+pub type UpdateRequestResponse = WriteResponse<CandidateModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct UpdateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="candidates/meta/patch", return_type=MetaResponse)]
+pub struct MetaPatchRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/candidates?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"first_name\": \"Gil\",
+                      \"last_name\": \"Feig\",
+                      \"company\": \"Columbia Dining App.\",
+                      \"title\": \"Software Engineer\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"last_interaction_at\": \"2021-12-06T10:11:26Z\",
+                      \"is_private\": true,
+                      \"can_email\": true,
+                      \"locations\": [
+                        \"San Francisco\",
+                        \"New York\"
+                      ],
+                      \"phone_numbers\": [
+                        {
+                          \"value\": \"+3198675309\",
+                          \"phone_number_type\": \"HOME\"
+                        }
+                      ],
+                      \"email_addresses\": [
+                        {
+                          \"value\": \"merge_is_hiring@merge.dev\",
+                          \"email_address_type\": \"PERSONAL\"
+                        }
+                      ],
+                      \"urls\": [
+                        {
+                          \"value\": \"http://alturl.com/p749b\",
+                          \"url_type\": \"PERSONAL\"
+                        }
+                      ],
+                      \"tags\": [
+                        \"Rust\",
+                        \"Remote\"
+                      ],
+                      \"applications\": [
+                        \"29eb9867-ce2a-403f-b8ce-f2844b89f078\"
+                      ],
+                      \"attachments\": [
+                        \"bea08964-32b4-4a20-8bb4-2612ba09de1d\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/candidates\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = CandidateModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .first_name("Gil")
+            .last_name("Feig")
+            .company("Columbia Dining App.")
+            .title("Software Engineer")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .last_interaction_at("2021-12-06T10:11:26Z")
+            .is_private(true)
+            .can_email(true)
+            .locations(vec!["San Francisco".to_string(), "New York".to_string()])
+            .phone_numbers(vec![PhoneNumberBuilder::default()
+                .value("+3198675309")
+                .phone_number_type("HOME")
+                .build()
+                .unwrap()])
+            .email_addresses(vec![EmailAddressBuilder::default()
+                .value("merge_is_hiring@merge.dev")
+                .email_address_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .urls(vec![UrlBuilder::default()
+                .value("http://alturl.com/p749b")
+                .url_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .tags(vec!["Rust".to_string(), "Remote".to_string()])
+            .applications(vec![Expandable::from(
+                "29eb9867-ce2a-403f-b8ce-f2844b89f078",
+            )])
+            .attachments(vec![Expandable::from(
+                "bea08964-32b4-4a20-8bb4-2612ba09de1d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/candidates")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/candidates/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"first_name\": \"Gil\",
+              \"last_name\": \"Feig\",
+              \"company\": \"Columbia Dining App.\",
+              \"title\": \"Software Engineer\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"last_interaction_at\": \"2021-12-06T10:11:26Z\",
+              \"is_private\": true,
+              \"can_email\": true,
+              \"locations\": [
+                \"San Francisco\",
+                \"New York\"
+              ],
+              \"phone_numbers\": [
+                {
+                  \"value\": \"+3198675309\",
+                  \"phone_number_type\": \"HOME\"
+                }
+              ],
+              \"email_addresses\": [
+                {
+                  \"value\": \"merge_is_hiring@merge.dev\",
+                  \"email_address_type\": \"PERSONAL\"
+                }
+              ],
+              \"urls\": [
+                {
+                  \"value\": \"http://alturl.com/p749b\",
+                  \"url_type\": \"PERSONAL\"
+                }
+              ],
+              \"tags\": [
+                \"Rust\",
+                \"Remote\"
+              ],
+              \"applications\": [
+                \"29eb9867-ce2a-403f-b8ce-f2844b89f078\"
+              ],
+              \"attachments\": [
+                \"bea08964-32b4-4a20-8bb4-2612ba09de1d\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/candidates\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CandidateModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .first_name("Gil")
+            .last_name("Feig")
+            .company("Columbia Dining App.")
+            .title("Software Engineer")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .last_interaction_at("2021-12-06T10:11:26Z")
+            .is_private(true)
+            .can_email(true)
+            .locations(vec!["San Francisco".to_string(), "New York".to_string()])
+            .phone_numbers(vec![PhoneNumberBuilder::default()
+                .value("+3198675309")
+                .phone_number_type("HOME")
+                .build()
+                .unwrap()])
+            .email_addresses(vec![EmailAddressBuilder::default()
+                .value("merge_is_hiring@merge.dev")
+                .email_address_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .urls(vec![UrlBuilder::default()
+                .value("http://alturl.com/p749b")
+                .url_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .tags(vec!["Rust".to_string(), "Remote".to_string()])
+            .applications(vec![Expandable::from(
+                "29eb9867-ce2a-403f-b8ce-f2844b89f078",
+            )])
+            .attachments(vec![Expandable::from(
+                "bea08964-32b4-4a20-8bb4-2612ba09de1d",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/candidates")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: CandidateModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_candidates() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/candidates?email_addresses=merge_is_hiring%40merge.dev&tags=Rust,Remote",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .email_addresses("merge_is_hiring@merge.dev")
+            .tags("Rust,Remote")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_candidate() {
+        let m = mock("POST", "/api/ats/v1/candidates?is_debug_mode=true")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "first_name": "Gil",
+                    "last_name": "Feig",
+                    "email_addresses": [
+                        {
+                            "value": "merge_is_hiring@merge.dev",
+                            "email_address_type": "PERSONAL"
+                        }
+                    ]
+                },
+                "remote_user_id": "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee"
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4\",
+                    \"first_name\": \"Gil\",
+                    \"last_name\": \"Feig\",
+                    \"email_addresses\": [
+                      {
+                        \"value\": \"merge_is_hiring@merge.dev\",
+                        \"email_address_type\": \"PERSONAL\"
+                      }
+                    ],
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": [],
+                  \"logs\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let candidate: CandidateRequest = CandidateRequestBuilder::default()
+            .first_name("Gil")
+            .last_name("Feig")
+            .email_addresses(vec![EmailAddressBuilder::default()
+                .value("merge_is_hiring@merge.dev")
+                .email_address_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let params: CreateRequestParams = CreateRequestParamsBuilder::default()
+            .is_debug_mode(true)
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(candidate)
+            .remote_user_id("bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4");
+        assert_eq!(response.model.first_name, Some("Gil".to_string()));
+        assert!(response.warnings.is_empty());
+        assert!(response.errors.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_update_a_candidate() {
+        let m = mock(
+            "PATCH",
+            "/api/ats/v1/candidates/521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4",
+        )
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": {
+                "title": "Staff Engineer"
+            },
+            "remote_user_id": "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"model\": {
+                    \"id\": \"521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4\",
+                    \"first_name\": \"Gil\",
+                    \"last_name\": \"Feig\",
+                    \"email_addresses\": [
+                      {
+                        \"value\": \"merge_is_hiring@merge.dev\",
+                        \"email_address_type\": \"PERSONAL\"
+                      }
+                    ],
+                    \"remote_was_deleted\": false,
+                    \"title\": \"Staff Engineer\"
+                  },
+                  \"warnings\": [],
+                  \"errors\": [
+                    {
+                      \"source\": {
+                        \"pointer\": \"/model/tags\"
+                      },
+                      \"title\": \"Missing Required Field\",
+                      \"detail\": \"tags is a required field on model.\",
+                      \"problem_type\": \"MISSING_REQUIRED_FIELD\"
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let candidate: CandidateRequest = CandidateRequestBuilder::default()
+            .title("Staff Engineer")
+            .build()
+            .unwrap();
+
+        let request = UpdateRequestBuilder::default()
+            .config(config)
+            .id("521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4")
+            .model(candidate)
+            .remote_user_id("bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.title, Some("Staff Engineer".to_string()));
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].source.clone().unwrap().pointer,
+            "/model/tags"
+        );
+        assert!(response.logs.is_none());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/ats/v1/candidates/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"first_name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": true,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "first_name"
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(response.has_conditional_params);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_patch_schema() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/candidates/meta/patch/521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"first_name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": true,
+                  \"has_required_linked_account_params\": false
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPatchRequestBuilder::default()
+            .config(config)
+            .id("521b18c2-4d01-4aaa-8848-bd3c5fbe5ef4")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "first_name"
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(response.has_conditional_params);
+        m.assert()
+    }
+}
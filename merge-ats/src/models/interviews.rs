@@ -0,0 +1,302 @@
+use crate::configuration::ATSConfig;
+use crate::models::applications::ApplicationModel;
+use crate::models::job_interview_stages::JobInterviewStageModel;
+use crate::models::remote_users::RemoteUserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ScheduledInterviewModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub application: Option<Expandable<ApplicationModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub job_interview_stage: Option<Expandable<JobInterviewStageModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub organizer: Option<Expandable<RemoteUserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub interviewers: Option<Vec<Expandable<RemoteUserModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub location: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="interviews", return_type=Paginated<ScheduledInterviewModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `ScheduledInterviewModel` that `expand` can return in full.
+    pub enum ScheduledInterviewExpand {
+        Application => "application",
+        JobInterviewStage => "job_interview_stage",
+        Organizer => "organizer",
+        Interviewers => "interviewers",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub application_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ScheduledInterviewExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub job_interview_stage_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub organizer_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<ScheduledInterviewModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="interviews", return_type=ScheduledInterviewModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<ScheduledInterviewExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/interviews?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"application\": \"3676d55f-8449-4cbe-9dfc-614c1b1b62fc\",
+                      \"job_interview_stage\": \"a8c405d4-4a26-4034-91df-97ab86512f10\",
+                      \"organizer\": \"86099590-d2ae-47c4-97f4-e993d395163d\",
+                      \"interviewers\": [
+                        \"f9813dd5-e70b-484c-91d8-00acd6065b07\"
+                      ],
+                      \"location\": \"Embarcadero Center 2\",
+                      \"start_at\": \"2021-12-06T10:11:26Z\",
+                      \"end_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"status\": \"SCHEDULED\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/interviews\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = ScheduledInterviewModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .application("3676d55f-8449-4cbe-9dfc-614c1b1b62fc")
+            .job_interview_stage("a8c405d4-4a26-4034-91df-97ab86512f10")
+            .organizer("86099590-d2ae-47c4-97f4-e993d395163d")
+            .interviewers(vec![Expandable::from(
+                "f9813dd5-e70b-484c-91d8-00acd6065b07",
+            )])
+            .location("Embarcadero Center 2")
+            .start_at("2021-12-06T10:11:26Z")
+            .end_at("2021-12-06T10:11:26Z")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .status("SCHEDULED")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/interviews")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/interviews/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"application\": \"3676d55f-8449-4cbe-9dfc-614c1b1b62fc\",
+              \"job_interview_stage\": \"a8c405d4-4a26-4034-91df-97ab86512f10\",
+              \"organizer\": \"86099590-d2ae-47c4-97f4-e993d395163d\",
+              \"interviewers\": [
+                \"f9813dd5-e70b-484c-91d8-00acd6065b07\"
+              ],
+              \"location\": \"Embarcadero Center 2\",
+              \"start_at\": \"2021-12-06T10:11:26Z\",
+              \"end_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"status\": \"SCHEDULED\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/interviews\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = ScheduledInterviewModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .application("3676d55f-8449-4cbe-9dfc-614c1b1b62fc")
+            .job_interview_stage("a8c405d4-4a26-4034-91df-97ab86512f10")
+            .organizer("86099590-d2ae-47c4-97f4-e993d395163d")
+            .interviewers(vec![Expandable::from(
+                "f9813dd5-e70b-484c-91d8-00acd6065b07",
+            )])
+            .location("Embarcadero Center 2")
+            .start_at("2021-12-06T10:11:26Z")
+            .end_at("2021-12-06T10:11:26Z")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .status("SCHEDULED")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/interviews")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: ScheduledInterviewModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
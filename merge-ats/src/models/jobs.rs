@@ -0,0 +1,394 @@
+use crate::configuration::ATSConfig;
+use crate::models::departments::DepartmentModel;
+use crate::models::offices::OfficeModel;
+use crate::models::remote_users::RemoteUserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct JobModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub code: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub job_posting_urls: Option<Vec<Url>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub confidential: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub departments: Option<Vec<Expandable<DepartmentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub offices: Option<Vec<Expandable<OfficeModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub hiring_managers: Option<Vec<Expandable<RemoteUserModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub recruiters: Option<Vec<Expandable<RemoteUserModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Url {
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub url_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="jobs", return_type=Paginated<JobModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `JobModel` that `expand` can return in full.
+    pub enum JobExpand {
+        Departments => "departments",
+        Offices => "offices",
+        HiringManagers => "hiring_managers",
+        Recruiters => "recruiters",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub code: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<JobExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub offices: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<JobModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ats", model="jobs", return_type=JobModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<ATSConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<JobExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ats/v1/jobs?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Software Engineer\",
+                      \"description\": \"Inspiring, fun, and creative.\",
+                      \"code\": \"C0025\",
+                      \"status\": \"OPEN\",
+                      \"job_posting_urls\": [
+                        {
+                          \"value\": \"http://alturl.com/p749b\",
+                          \"url_type\": \"PERSONAL\"
+                        }
+                      ],
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"confidential\": true,
+                      \"departments\": [
+                        \"5b3c1341-a20f-4e51-b72c-f3830a16c97b\"
+                      ],
+                      \"offices\": [
+                        \"9871b4a9-f5d2-4f3b-a66b-dfedbed42c46\"
+                      ],
+                      \"hiring_managers\": [
+                        \"787ed912-8ba2-4c8d-a8ab-e5fd89a62fee\"
+                      ],
+                      \"recruiters\": [
+                        \"bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/jobs\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = JobModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Software Engineer")
+            .description("Inspiring, fun, and creative.")
+            .code("C0025")
+            .status("OPEN")
+            .job_posting_urls(vec![UrlBuilder::default()
+                .value("http://alturl.com/p749b")
+                .url_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .confidential(true)
+            .departments(vec![Expandable::from(
+                "5b3c1341-a20f-4e51-b72c-f3830a16c97b",
+            )])
+            .offices(vec![Expandable::from(
+                "9871b4a9-f5d2-4f3b-a66b-dfedbed42c46",
+            )])
+            .hiring_managers(vec![Expandable::from(
+                "787ed912-8ba2-4c8d-a8ab-e5fd89a62fee",
+            )])
+            .recruiters(vec![Expandable::from(
+                "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/jobs")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/jobs/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Software Engineer\",
+              \"description\": \"Inspiring, fun, and creative.\",
+              \"code\": \"C0025\",
+              \"status\": \"OPEN\",
+              \"job_posting_urls\": [
+                {
+                  \"value\": \"http://alturl.com/p749b\",
+                  \"url_type\": \"PERSONAL\"
+                }
+              ],
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"confidential\": true,
+              \"departments\": [
+                \"5b3c1341-a20f-4e51-b72c-f3830a16c97b\"
+              ],
+              \"offices\": [
+                \"9871b4a9-f5d2-4f3b-a66b-dfedbed42c46\"
+              ],
+              \"hiring_managers\": [
+                \"787ed912-8ba2-4c8d-a8ab-e5fd89a62fee\"
+              ],
+              \"recruiters\": [
+                \"bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/jobs\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = JobModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Software Engineer")
+            .description("Inspiring, fun, and creative.")
+            .code("C0025")
+            .status("OPEN")
+            .job_posting_urls(vec![UrlBuilder::default()
+                .value("http://alturl.com/p749b")
+                .url_type("PERSONAL")
+                .build()
+                .unwrap()])
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .confidential(true)
+            .departments(vec![Expandable::from(
+                "5b3c1341-a20f-4e51-b72c-f3830a16c97b",
+            )])
+            .offices(vec![Expandable::from(
+                "9871b4a9-f5d2-4f3b-a66b-dfedbed42c46",
+            )])
+            .hiring_managers(vec![Expandable::from(
+                "787ed912-8ba2-4c8d-a8ab-e5fd89a62fee",
+            )])
+            .recruiters(vec![Expandable::from(
+                "bbb519a4-8ba2-4c8d-a8ab-e5fd89a62fee",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/jobs")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: JobModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_jobs() {
+        let m = mock("GET", "/api/ats/v1/jobs?code=C0025&status=OPEN")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+            .expect(1)
+            .create();
+
+        let config = ATSConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .code("C0025")
+            .status("OPEN")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+}
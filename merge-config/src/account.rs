@@ -0,0 +1,558 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+use crate::configuration::Configuration;
+use crate::pagination::Paginated;
+use crate::query::QueryValue;
+
+/// A Merge category. The account-level endpoints exist once per category and manage the linked
+/// accounts of that category only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Hris,
+    Ats,
+    Accounting,
+    Crm,
+    Ticketing,
+    FileStorage,
+}
+
+impl Category {
+    /// The name used in Merge's paths and in the `category` filter, e.g. `hris`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Hris => "hris",
+            Category::Ats => "ats",
+            Category::Accounting => "accounting",
+            Category::Crm => "crm",
+            Category::Ticketing => "ticketing",
+            Category::FileStorage => "filestorage",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl QueryValue for Category {
+    fn to_query_value(&self) -> String {
+        self.as_str().to_string()
+    }
+}
+
+/// The third party a linked account is connected to.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AccountIntegration {
+    pub name: String,
+    #[builder(setter(into, strip_option), default)]
+    pub abbreviated_name: Option<String>,
+    #[serde(default)]
+    #[builder(default)]
+    pub categories: Vec<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub image: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub square_image: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub color: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub slug: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct LinkedAccountModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub category: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub integration: Option<AccountIntegration>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_origin_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_organization_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub subdomain: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub webhook_listener_url: Option<String>,
+    /// Whether another linked account of the same end user and integration exists.
+    #[builder(setter(into, strip_option), default)]
+    pub is_duplicate: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub account_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub completed_at: Option<String>,
+}
+
+/// Lists every linked account of a category.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="linked-accounts", return_type=Paginated<LinkedAccountModel>)]
+pub struct LinkedAccountsRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<LinkedAccountsRequestParams>,
+}
+
+pub type LinkedAccountsRequestResponse = Paginated<LinkedAccountModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct LinkedAccountsRequestParams {
+    /// Only accounts of this category, for integrations offering several.
+    #[builder(setter(into, strip_option), default)]
+    pub category: Option<Category>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_organization_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_origin_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_origin_ids: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ids: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_duplicates: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub integration_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_test_account: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    /// `COMPLETE`, `INCOMPLETE` or `RELINK_NEEDED`.
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+}
+
+/// Deletes the linked account the configured access token belongs to. This cannot be undone.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="delete-account", return_type=(), method="POST")]
+pub struct DeleteAccountRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+}
+
+/// The state of the syncs of one model for a linked account.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct SyncStatusModel {
+    pub model_name: String,
+    pub model_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub last_sync_start: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub next_sync_start: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_sync_result: Option<String>,
+    /// `SYNCING`, `DONE`, `FAILED`, `DISABLED`, `PAUSED` or `PARTIALLY_SYNCED`.
+    pub status: String,
+    pub is_initial_sync: bool,
+    #[builder(setter(into, strip_option), default)]
+    pub selective_sync_configurations_usage: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="sync-status", return_type=Paginated<SyncStatusModel>)]
+pub struct SyncStatusRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<SyncStatusRequestParams>,
+}
+
+pub type SyncStatusRequestResponse = Paginated<SyncStatusModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct SyncStatusRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+}
+
+/// Queues a sync of every model of the linked account, outside of its usual schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="sync-status/resync", return_type=Vec<SyncStatusModel>, method="POST")]
+pub struct ResyncRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AccountDetailsModel {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    /// The name of the integration.
+    #[builder(setter(into, strip_option), default)]
+    pub integration: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub integration_slug: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub category: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_origin_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_organization_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_user_email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub webhook_listener_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_duplicate: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub account_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub completed_at: Option<String>,
+}
+
+/// The details of the linked account the configured access token belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="account-details", return_type=AccountDetailsModel)]
+pub struct AccountDetailsRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+}
+
+/// What the integration supports for one model.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ModelOperation {
+    pub model_name: String,
+    /// e.g. `FETCH`, `CREATE` or `UPDATE`.
+    #[serde(default)]
+    #[builder(default)]
+    pub available_operations: Vec<String>,
+    #[serde(default)]
+    #[builder(default)]
+    pub required_post_parameters: Vec<String>,
+    #[serde(default)]
+    #[builder(default)]
+    pub supported_fields: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AvailableActionsModel {
+    pub integration: AccountIntegration,
+    #[builder(setter(into, strip_option), default)]
+    pub passthrough_available: Option<bool>,
+    #[serde(default)]
+    #[builder(default)]
+    pub available_model_operations: Vec<ModelOperation>,
+}
+
+impl AvailableActionsModel {
+    /// Whether the integration supports `operation`, e.g. `CREATE`, for `model_name`.
+    pub fn supports(&self, model_name: &str, operation: &str) -> bool {
+        self.available_model_operations.iter().any(|model| {
+            model.model_name == model_name
+                && model.available_operations.iter().any(|op| op == operation)
+        })
+    }
+}
+
+/// The models and operations the integration of the linked account supports.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="available-actions", return_type=AvailableActionsModel)]
+pub struct AvailableActionsRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+
+    const ACCOUNT_ID: &str = "0496d4c2-42e6-4072-80b3-7b69bfdc76fd";
+
+    fn config() -> Configuration {
+        Configuration::new("test", "test").with_base_url(mockito::server_url())
+    }
+
+    #[tokio::test]
+    async fn it_should_list_linked_accounts() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/linked-accounts?category=hris&end_user_origin_ids=1,2&status=COMPLETE",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"next\": null,
+              \"previous\": null,
+              \"results\": [
+                {
+                  \"id\": \"0496d4c2-42e6-4072-80b3-7b69bfdc76fd\",
+                  \"category\": \"hris\",
+                  \"integration\": {
+                    \"name\": \"BambooHR\",
+                    \"categories\": [\"hris\", \"ats\"],
+                    \"color\": \"#3AA42C\",
+                    \"slug\": \"bamboohr\"
+                  },
+                  \"status\": \"COMPLETE\",
+                  \"end_user_origin_id\": \"1\",
+                  \"end_user_organization_name\": \"Waystar Royco\",
+                  \"is_duplicate\": false
+                }
+              ]
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let params: LinkedAccountsRequestParams = LinkedAccountsRequestParamsBuilder::default()
+            .category(Category::Hris)
+            .end_user_origin_ids(vec!["1".to_string(), "2".to_string()])
+            .status("COMPLETE")
+            .build()
+            .unwrap();
+
+        let request = LinkedAccountsRequestBuilder::default()
+            .config(config())
+            .category(Category::Hris)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: LinkedAccountsRequestResponse = request.send_request().await.unwrap();
+
+        let account = &response.results[0];
+        assert_eq!(account.id, ACCOUNT_ID);
+        assert_eq!(
+            account.integration.as_ref().unwrap().categories,
+            vec!["hris", "ats"]
+        );
+        assert_eq!(account.is_duplicate, Some(false));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_an_account() {
+        let m = mock("POST", "/api/ats/v1/delete-account")
+            .match_header("x-account-token", "test")
+            .with_status(200)
+            .expect(1)
+            .create();
+
+        let request = DeleteAccountRequestBuilder::default()
+            .config(config())
+            .category(Category::Ats)
+            .build()
+            .unwrap();
+
+        request.send_request().await.unwrap();
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_force_a_resync() {
+        let m = mock("POST", "/api/filestorage/v1/sync-status/resync")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                [
+                  {
+                    \"model_name\": \"File\",
+                    \"model_id\": \"filestorage.File\",
+                    \"last_sync_start\": \"2021-03-30T19:44:18.695973Z\",
+                    \"status\": \"SYNCING\",
+                    \"is_initial_sync\": false
+                  }
+                ]",
+            )
+            .expect(1)
+            .create();
+
+        let request = ResyncRequestBuilder::default()
+            .config(config())
+            .category(Category::FileStorage)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response[0].model_id, "filestorage.File");
+        assert_eq!(response[0].status, "SYNCING");
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_get_the_sync_status() {
+        let m = mock("GET", "/api/accounting/v1/sync-status?page_size=1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": null,
+                  \"results\": [
+                    {
+                      \"model_name\": \"Invoice\",
+                      \"model_id\": \"accounting.Invoice\",
+                      \"last_sync_start\": \"2021-03-30T19:44:18.695973Z\",
+                      \"next_sync_start\": \"2021-03-30T20:44:18.662942Z\",
+                      \"last_sync_result\": \"DONE\",
+                      \"status\": \"DONE\",
+                      \"is_initial_sync\": true,
+                      \"selective_sync_configurations_usage\": \"IN_NEXT_SYNC\"
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let params: SyncStatusRequestParams = SyncStatusRequestParamsBuilder::default()
+            .page_size(1)
+            .build()
+            .unwrap();
+
+        let request = SyncStatusRequestBuilder::default()
+            .config(config())
+            .category(Category::Accounting)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: SyncStatusRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.next.is_some());
+        assert!(response.results[0].is_initial_sync);
+        assert_eq!(
+            response.results[0].last_sync_result,
+            Some("DONE".to_string())
+        );
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_get_the_account_details() {
+        let m = mock("GET", "/api/crm/v1/account-details")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"id\": \"0496d4c2-42e6-4072-80b3-7b69bfdc76fd\",
+                  \"integration\": \"HubSpot\",
+                  \"integration_slug\": \"hubspot\",
+                  \"category\": \"crm\",
+                  \"end_user_origin_id\": \"1\",
+                  \"end_user_organization_name\": \"Waystar Royco\",
+                  \"end_user_email_address\": \"hello@merge.dev\",
+                  \"status\": \"COMPLETE\",
+                  \"webhook_listener_url\": \"https://api.merge.dev/api/integrations/webhook-listener/7fc5b2a7\",
+                  \"is_duplicate\": null,
+                  \"account_type\": \"PRODUCTION\",
+                  \"completed_at\": \"2023-10-02T15:44:10.530357Z\"
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let request = AccountDetailsRequestBuilder::default()
+            .config(config())
+            .category(Category::Crm)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.id, Some(ACCOUNT_ID.to_string()));
+        assert_eq!(response.integration_slug, Some("hubspot".to_string()));
+        assert_eq!(response.is_duplicate, None);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_get_the_available_actions() {
+        let m = mock("GET", "/api/ticketing/v1/available-actions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"integration\": {
+                    \"name\": \"Jira\",
+                    \"categories\": [\"ticketing\"],
+                    \"slug\": \"jira\"
+                  },
+                  \"passthrough_available\": true,
+                  \"available_model_operations\": [
+                    {
+                      \"model_name\": \"Ticket\",
+                      \"available_operations\": [\"FETCH\", \"CREATE\"],
+                      \"required_post_parameters\": [\"name\"],
+                      \"supported_fields\": [\"name\", \"status\"]
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let request = AvailableActionsRequestBuilder::default()
+            .config(config())
+            .category(Category::Ticketing)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.integration.slug, Some("jira".to_string()));
+        assert!(response.supports("Ticket", "CREATE"));
+        assert!(!response.supports("Ticket", "UPDATE"));
+        assert!(!response.supports("Comment", "FETCH"));
+        m.assert()
+    }
+
+    #[test]
+    fn it_names_categories_as_merge_does() {
+        assert_eq!(Category::FileStorage.to_string(), "filestorage");
+        assert_eq!(
+            serde_json::from_str::<Category>("\"hris\"").unwrap(),
+            Category::Hris
+        );
+    }
+}
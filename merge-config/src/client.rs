@@ -0,0 +1,392 @@
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::Proxy;
+use serde::de::DeserializeOwned;
+
+use crate::configuration::Configuration;
+use crate::download::Download;
+use crate::error::MergeError;
+use crate::pagination::Paginator;
+use crate::retry::{RateLimit, RetryPolicy};
+use crate::upload::Form;
+
+pub use reqwest::header::HeaderMap;
+pub use reqwest::Method;
+
+const DEFAULT_USER_AGENT: &str = concat!("merge-dev/", env!("CARGO_PKG_VERSION"));
+
+/// A request that can be sent through a `MergeClient`.
+///
+/// Implemented by the `send_request` attribute macro for every generated request struct.
+pub trait MergeRequest {
+    /// The decoded body for JSON endpoints, `Download` for file downloads.
+    type Response;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    /// The path below the configured base url, including the query string.
+    fn path(&self) -> String;
+
+    /// The JSON body sent with writes. A body that cannot be encoded fails the request before
+    /// anything is sent.
+    fn body(&self) -> Result<Option<serde_json::Value>, MergeError> {
+        Ok(None)
+    }
+
+    /// A multipart body, sent instead of `body` for file uploads. It is built again for every
+    /// attempt since a form is consumed when it is sent.
+    fn multipart(&self) -> Result<Option<Form>, MergeError> {
+        Ok(None)
+    }
+
+    /// Headers sent with this request only, e.g. `Range` for resumed downloads.
+    fn headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+
+    /// The configuration set on the request itself, if any. It takes precedence over the
+    /// configuration owned by the client.
+    fn configuration(&self) -> Option<&Configuration>;
+}
+
+/// Owns a single `reqwest::Client` so connections, TLS sessions and keep-alive are shared
+/// between requests. Clones share the connection pool and the rate-limit state.
+#[derive(Debug, Clone)]
+pub struct MergeClient {
+    http: reqwest::Client,
+    config: Configuration,
+    retry_policy: RetryPolicy,
+    rate_limit: Arc<Mutex<RateLimit>>,
+}
+
+impl MergeClient {
+    pub fn new(config: Configuration) -> Self {
+        MergeClient {
+            http: shared_http_client().clone(),
+            config,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: Arc::default(),
+        }
+    }
+
+    pub fn builder(config: Configuration) -> MergeClientBuilder {
+        MergeClientBuilder::new(config)
+    }
+
+    pub fn configuration(&self) -> &Configuration {
+        &self.config
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The rate-limit headers of the last response seen by this client, so callers can
+    /// throttle themselves before Merge starts answering with 429.
+    pub fn rate_limit(&self) -> RateLimit {
+        self.rate_limit.lock().unwrap().clone()
+    }
+
+    pub async fn send<R>(&self, request: &R) -> Result<R::Response, MergeError>
+    where
+        R: MergeRequest,
+        R::Response: DeserializeOwned,
+    {
+        let response = self.execute_with_retries(request).await?;
+        let body = response.text().await?;
+        // Some endpoints, e.g. `POST /delete-account`, answer with an empty body.
+        if body.trim().is_empty() {
+            return MergeError::deserialize("null".to_string());
+        }
+        MergeError::deserialize(body)
+    }
+
+    /// Starts downloading a file. Only the headers have been read when this returns; the body
+    /// is streamed through the returned `Download`.
+    pub async fn download<R>(&self, request: &R) -> Result<Download, MergeError>
+    where
+        R: MergeRequest<Response = Download>,
+    {
+        Ok(Download::new(self.execute_with_retries(request).await?))
+    }
+
+    async fn execute_with_retries<R>(&self, request: &R) -> Result<reqwest::Response, MergeError>
+    where
+        R: MergeRequest,
+    {
+        let method = request.method();
+        let mut attempt = 1;
+        loop {
+            let error = match self.execute(request).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+            match self.retry_policy.retry_delay(attempt, &method, &error) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Sends `request` once and returns the response if its status is a success.
+    async fn execute<R>(&self, request: &R) -> Result<reqwest::Response, MergeError>
+    where
+        R: MergeRequest,
+    {
+        let config = request.configuration().unwrap_or(&self.config);
+
+        let mut builder = self
+            .http
+            .request(
+                request.method(),
+                format!("{}{}", config.base_url, request.path()),
+            )
+            .bearer_auth(&config.api_key)
+            .header("X-Account-Token", &config.access_token)
+            .headers(request.headers());
+        if let Some(form) = request.multipart()? {
+            builder = builder.multipart(form);
+        } else if let Some(body) = request.body()? {
+            builder = builder.json(&body);
+        }
+
+        let response = builder.send().await?;
+
+        let rate_limit = RateLimit::from_headers(response.headers());
+        if !rate_limit.is_empty() {
+            *self.rate_limit.lock().unwrap() = rate_limit;
+        }
+
+        if !response.status().is_success() {
+            return Err(MergeError::from_response(response).await);
+        }
+        Ok(response)
+    }
+
+    /// Walks a list request page by page, see `Paginator`.
+    pub fn paginate<R>(&self, request: R) -> Paginator<R> {
+        Paginator::new(self.clone(), request)
+    }
+}
+
+/// The client used by `MergeClient::new` and by the generated `send_request` methods, so
+/// even callers that never build a `MergeClient` reuse one connection pool.
+fn shared_http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .user_agent(DEFAULT_USER_AGENT)
+            .build()
+            .expect("the default http client should always build")
+    })
+}
+
+pub struct MergeClientBuilder {
+    config: Configuration,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: String,
+    proxies: Vec<Proxy>,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+}
+
+impl MergeClientBuilder {
+    pub fn new(config: Configuration) -> Self {
+        MergeClientBuilder {
+            config,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxies: vec![],
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Total time allowed for a request, from connecting until the body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// A header sent with every request made through the client.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// How failed requests are retried. Defaults to `RetryPolicy::default()`.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn build(self) -> Result<MergeClient, MergeError> {
+        let mut http = reqwest::Client::builder()
+            .user_agent(self.user_agent)
+            .default_headers(self.default_headers);
+
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+
+        Ok(MergeClient {
+            http: http.build()?,
+            config: self.config,
+            retry_policy: self.retry_policy,
+            rate_limit: Arc::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::upload::FileUpload;
+    use merge_proc_macros::send_request;
+    use mockito::mock;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone)]
+    #[send_request(service="hris", model="unencodable", return_type=serde_json::Value, method="POST")]
+    struct UnencodableRequest {
+        config: Option<Configuration>,
+        // JSON object keys must be strings.
+        model: BTreeMap<Vec<u8>, String>,
+    }
+
+    #[derive(Debug, Clone)]
+    #[send_request(service="filestorage", model="unencodable", return_type=serde_json::Value, method="POST")]
+    struct UnencodableUploadRequest {
+        config: Option<Configuration>,
+        model: BTreeMap<Vec<u8>, String>,
+        file: FileUpload,
+    }
+
+    #[derive(Debug, Clone)]
+    #[send_request(service="ats", model="jobs/{job_id}/notes", return_type=serde_json::Value)]
+    struct NoteRequest {
+        config: Option<Configuration>,
+        job_id: String,
+        id: String,
+    }
+
+    #[tokio::test]
+    async fn it_encodes_ids_placed_in_the_path() {
+        let m = mock(
+            "GET",
+            "/api/ats/v1/jobs/42%2F..%2Fadmin/notes/a%20b%3Fc%23d",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .expect(1)
+        .create();
+
+        let request = NoteRequest {
+            config: Some(
+                Configuration::new("someKey", "someToken").with_base_url(mockito::server_url()),
+            ),
+            job_id: "42/../admin".to_string(),
+            id: "a b?c#d".to_string(),
+        };
+
+        assert_eq!(
+            request.path(),
+            "/api/ats/v1/jobs/42%2F..%2Fadmin/notes/a%20b%3Fc%23d"
+        );
+        request.send_request().await.unwrap();
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_fails_without_sending_a_body_that_cannot_be_encoded() {
+        let m = mock("POST", "/api/hris/v1/unencodable").expect(0).create();
+
+        let request = UnencodableRequest {
+            config: Some(
+                Configuration::new("someKey", "someToken").with_base_url(mockito::server_url()),
+            ),
+            model: BTreeMap::from([(vec![1, 2], "value".to_string())]),
+        };
+
+        let err = request.send_request().await.unwrap_err();
+
+        assert!(matches!(err, MergeError::Serialize(_)));
+        assert!(err
+            .to_string()
+            .starts_with("Failed to serialize request body"));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_fails_without_uploading_a_model_that_cannot_be_encoded() {
+        let m = mock("POST", "/api/filestorage/v1/unencodable")
+            .expect(0)
+            .create();
+
+        let request = UnencodableUploadRequest {
+            config: Some(
+                Configuration::new("someKey", "someToken").with_base_url(mockito::server_url()),
+            ),
+            model: BTreeMap::from([(vec![1, 2], "value".to_string())]),
+            file: FileUpload::new("notes.txt", "hello"),
+        };
+
+        let err = request.send_request().await.unwrap_err();
+
+        assert!(matches!(err, MergeError::Serialize(_)));
+        m.assert()
+    }
+
+    #[test]
+    fn it_builds_a_configured_client() {
+        let client = MergeClient::builder(Configuration::new("someKey", "someToken"))
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5))
+            .user_agent("my-app/1.0")
+            .retry_policy(RetryPolicy::none())
+            .proxy(Proxy::all("http://localhost:8080").unwrap())
+            .default_header(
+                HeaderName::from_static("x-request-source"),
+                HeaderValue::from_static("nightly"),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(client.configuration().api_key, "someKey");
+        assert_eq!(client.configuration().access_token, "someToken");
+        assert_eq!(client.retry_policy().max_attempts, 1);
+        assert!(client.rate_limit().is_empty());
+    }
+}
@@ -1,16 +1,62 @@
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub const US_BASE_URL: &str = "https://api.merge.dev";
+pub const EU_BASE_URL: &str = "https://api-eu.merge.dev";
+pub const APAC_BASE_URL: &str = "https://api-ap.merge.dev";
+
+/// The Merge region an account's data lives in.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    #[default]
+    Us,
+    Eu,
+    Apac
+}
+
+impl Region {
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            Region::Us => US_BASE_URL,
+            Region::Eu => EU_BASE_URL,
+            Region::Apac => APAC_BASE_URL
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Configuration {
     pub api_key: String,
-    pub access_token: String
+    pub access_token: String,
+    pub base_url: String
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            api_key: String::new(),
+            access_token: String::new(),
+            base_url: US_BASE_URL.to_string()
+        }
+    }
 }
 
 impl Configuration {
     pub fn new<K, T>(api_key: K, access_token: T) -> Self where K: ToString, T: ToString {
         Configuration {
             api_key: api_key.to_string(),
-            access_token: access_token.to_string()
+            access_token: access_token.to_string(),
+            base_url: US_BASE_URL.to_string()
         }
     }
+
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.base_url = region.base_url().to_string();
+        self
+    }
+
+    /// Points every request at `base_url` instead of a Merge region, e.g. a local stub server.
+    pub fn with_base_url<U>(mut self, base_url: U) -> Self where U: ToString {
+        self.base_url = base_url.to_string().trim_end_matches('/').to_string();
+        self
+    }
 }
 
 #[cfg(test)]
@@ -60,4 +106,26 @@ mod tests {
         assert_eq!(config.api_key, "someKey");
         assert_eq!(config.access_token, "someToken")
     }
+
+    #[test]
+    fn it_defaults_to_the_us_region() {
+        let config = Configuration::new("someKey", "someToken");
+        assert_eq!(config.base_url, "https://api.merge.dev");
+        assert_eq!(Configuration::default().base_url, "https://api.merge.dev")
+    }
+
+    #[test]
+    fn it_uses_regional_base_urls() {
+        let eu = Configuration::new("someKey", "someToken").with_region(Region::Eu);
+        assert_eq!(eu.base_url, "https://api-eu.merge.dev");
+        let apac = Configuration::new("someKey", "someToken").with_region(Region::Apac);
+        assert_eq!(apac.base_url, "https://api-ap.merge.dev")
+    }
+
+    #[test]
+    fn it_overrides_the_base_url() {
+        let config = Configuration::new("someKey", "someToken")
+            .with_base_url("http://localhost:1234/");
+        assert_eq!(config.base_url, "http://localhost:1234")
+    }
 }
\ No newline at end of file
@@ -0,0 +1,222 @@
+use std::fmt;
+use std::io;
+use std::ops::{RangeFrom, RangeInclusive};
+
+use bytes::Bytes;
+use futures::stream::{Stream, TryStreamExt};
+use percent_encoding::percent_decode_str;
+use reqwest::header::{
+    HeaderMap, HeaderValue, CONTENT_DISPOSITION, CONTENT_RANGE, CONTENT_TYPE, RANGE,
+};
+use reqwest::{Response, StatusCode};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::StreamReader;
+
+use crate::error::MergeError;
+
+/// A file being downloaded through Merge. The body is read lazily, chunk by chunk, so large
+/// files never have to be held in memory at once.
+#[derive(Debug)]
+pub struct Download {
+    response: Response,
+}
+
+impl Download {
+    pub fn new(response: Response) -> Self {
+        Download { response }
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.response.headers()
+    }
+
+    /// The size of the body, when the server announced it.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Whether the server answered a `Range` request with only part of the file.
+    pub fn is_partial(&self) -> bool {
+        self.response.status() == StatusCode::PARTIAL_CONTENT
+    }
+
+    /// The part of the file this body covers, when the server answered with `Content-Range`.
+    pub fn content_range(&self) -> Option<ContentRange> {
+        ContentRange::parse(self.response.headers().get(CONTENT_RANGE)?.to_str().ok()?)
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.response.headers().get(CONTENT_TYPE)?.to_str().ok()
+    }
+
+    /// The file name given in `Content-Disposition`, if any.
+    pub fn file_name(&self) -> Option<String> {
+        let disposition = self.response.headers().get(CONTENT_DISPOSITION)?;
+        file_name(disposition.to_str().ok()?)
+    }
+
+    /// The next chunk of the body, or `None` once it has been read entirely.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, MergeError> {
+        Ok(self.response.chunk().await?)
+    }
+
+    pub fn bytes_stream(self) -> impl Stream<Item = Result<Bytes, MergeError>> {
+        self.response.bytes_stream().map_err(MergeError::from)
+    }
+
+    /// The body as an `AsyncRead`, for APIs that want a reader rather than a stream.
+    pub fn into_async_read(self) -> impl AsyncRead + Send + Unpin {
+        StreamReader::new(self.response.bytes_stream().map_err(io::Error::other))
+    }
+
+    /// Writes the body to `writer` as it arrives and returns the number of bytes written.
+    pub async fn copy_to<W>(mut self, writer: &mut W) -> Result<u64, MergeError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.chunk().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
+
+/// The part of a file to download, sent as a `Range` header.
+///
+/// An interrupted download is resumed by requesting the file again from the number of bytes
+/// already written, e.g. `.range(written..)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    pub start: u64,
+    /// The last byte to download, inclusive. `None` reads until the end of the file.
+    pub end: Option<u64>,
+}
+
+impl ByteRange {
+    pub fn insert_into(&self, headers: &mut HeaderMap) {
+        let value = HeaderValue::from_str(&self.to_string())
+            .expect("a byte range is always a valid header value");
+        headers.insert(RANGE, value);
+    }
+}
+
+impl fmt::Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.end {
+            Some(end) => write!(f, "bytes={}-{}", self.start, end),
+            None => write!(f, "bytes={}-", self.start),
+        }
+    }
+}
+
+impl From<RangeFrom<u64>> for ByteRange {
+    fn from(range: RangeFrom<u64>) -> Self {
+        ByteRange {
+            start: range.start,
+            end: None,
+        }
+    }
+}
+
+impl From<RangeInclusive<u64>> for ByteRange {
+    fn from(range: RangeInclusive<u64>) -> Self {
+        ByteRange {
+            start: *range.start(),
+            end: Some(*range.end()),
+        }
+    }
+}
+
+/// A `Content-Range` header, e.g. `bytes 1024-2047/4096`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+    pub start: u64,
+    /// The last byte of the body, inclusive.
+    pub end: u64,
+    /// The size of the whole file, `None` when the server gave `*`.
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    fn parse(value: &str) -> Option<Self> {
+        let (range, total) = value.trim().strip_prefix("bytes ")?.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        Some(ContentRange {
+            start: start.trim().parse().ok()?,
+            end: end.trim().parse().ok()?,
+            total: match total.trim() {
+                "*" => None,
+                total => Some(total.parse().ok()?),
+            },
+        })
+    }
+}
+
+/// Reads the file name out of a `Content-Disposition` value, preferring the RFC 5987
+/// `filename*` form when both are present.
+fn file_name(disposition: &str) -> Option<String> {
+    let mut plain = None;
+    for part in disposition.split(';').map(str::trim) {
+        if let Some(value) = part.strip_prefix("filename*=") {
+            let encoded = value.split_once("''").map_or(value, |(_, encoded)| encoded);
+            if let Ok(decoded) = percent_decode_str(encoded).decode_utf8() {
+                return Some(decoded.into_owned());
+            }
+        } else if let Some(value) = part.strip_prefix("filename=") {
+            plain = Some(value.trim_matches('"').to_string());
+        }
+    }
+    plain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_the_file_name() {
+        assert_eq!(
+            file_name("attachment; filename=\"Resume.pdf\""),
+            Some("Resume.pdf".to_string())
+        );
+        assert_eq!(
+            file_name("attachment; filename=Resume.pdf"),
+            Some("Resume.pdf".to_string())
+        );
+        assert_eq!(
+            file_name("attachment; filename=\"Resume.pdf\"; filename*=UTF-8''R%C3%A9sum%C3%A9.pdf"),
+            Some("Résumé.pdf".to_string())
+        );
+        assert_eq!(file_name("inline"), None);
+    }
+
+    #[test]
+    fn it_formats_byte_ranges() {
+        assert_eq!(ByteRange::from(1024..).to_string(), "bytes=1024-");
+        assert_eq!(ByteRange::from(0..=99).to_string(), "bytes=0-99");
+
+        let mut headers = HeaderMap::new();
+        ByteRange::from(10..).insert_into(&mut headers);
+        assert_eq!(headers[RANGE], "bytes=10-");
+    }
+
+    #[test]
+    fn it_parses_content_ranges() {
+        assert_eq!(
+            ContentRange::parse("bytes 1024-2047/4096"),
+            Some(ContentRange {
+                start: 1024,
+                end: 2047,
+                total: Some(4096)
+            })
+        );
+        assert_eq!(
+            ContentRange::parse("bytes 0-99/*").and_then(|range| range.total),
+            None
+        );
+        assert_eq!(ContentRange::parse("bytes */4096"), None);
+    }
+}
@@ -0,0 +1,225 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::retry::retry_after;
+
+/// The error body Merge returns alongside a non-2xx status.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeErrorBody {
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub errors: Option<Vec<serde_json::Value>>,
+}
+
+/// The parts of a failed response worth keeping around for the caller.
+#[derive(Debug, Clone)]
+pub struct ErrorResponse {
+    pub status: u16,
+    pub body: Option<MergeErrorBody>,
+    pub raw_body: String,
+    pub headers: HeaderMap,
+}
+
+#[derive(Debug)]
+pub enum MergeError {
+    /// The request never produced a response (DNS, TLS, connection reset, timeout, ...).
+    Transport(reqwest::Error),
+    /// Merge answered with a non-2xx status other than 429.
+    Status(Box<ErrorResponse>),
+    /// The response body could not be decoded into the expected model.
+    Deserialize {
+        body: String,
+        path: String,
+        message: String,
+    },
+    /// The request body could not be encoded, so nothing was sent.
+    Serialize(serde_json::Error),
+    /// Neither the request nor the client carried a `Configuration`.
+    MissingConfiguration,
+    /// Merge answered with 429 Too Many Requests.
+    RateLimited {
+        retry_after: Option<Duration>,
+        response: Box<ErrorResponse>,
+    },
+    /// Writing a downloaded body failed.
+    Io(std::io::Error),
+    /// An async passthrough was still running when polling for its result gave up.
+    AsyncPassthroughPending { receipt_id: String },
+}
+
+impl MergeError {
+    /// The HTTP status code for errors that carry a response.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            MergeError::Status(response) => Some(response.status),
+            MergeError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS.as_u16()),
+            MergeError::Transport(err) => err.status().map(|status| status.as_u16()),
+            MergeError::Deserialize { .. }
+            | MergeError::Serialize(_)
+            | MergeError::MissingConfiguration
+            | MergeError::Io(_)
+            | MergeError::AsyncPassthroughPending { .. } => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND.as_u16())
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status() == Some(StatusCode::UNAUTHORIZED.as_u16())
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, MergeError::RateLimited { .. })
+    }
+
+    /// Builds the matching error for a response whose status is not a success.
+    pub async fn from_response(response: Response) -> MergeError {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let raw_body = match response.text().await {
+            Ok(body) => body,
+            Err(err) => return MergeError::Transport(err),
+        };
+        let body = serde_json::from_str::<MergeErrorBody>(&raw_body).ok();
+
+        let retry_after = retry_after(&headers);
+        let response = Box::new(ErrorResponse {
+            status: status.as_u16(),
+            body,
+            raw_body,
+            headers,
+        });
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            MergeError::RateLimited {
+                retry_after,
+                response,
+            }
+        } else {
+            MergeError::Status(response)
+        }
+    }
+
+    /// Decodes `body` into `T`, recording the path of the field that failed.
+    pub fn deserialize<T>(body: String) -> Result<T, MergeError>
+    where
+        T: DeserializeOwned,
+    {
+        let deserializer = &mut serde_json::Deserializer::from_str(&body);
+        match serde_path_to_error::deserialize::<_, T>(deserializer) {
+            Ok(value) => Ok(value),
+            Err(err) => Err(MergeError::Deserialize {
+                path: err.path().to_string(),
+                message: err.into_inner().to_string(),
+                body,
+            }),
+        }
+    }
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeError::Transport(err) => write!(f, "{}", err),
+            MergeError::Status(response) => write!(
+                f,
+                "Request was not successfully status: {} body: {}",
+                response.status, response.raw_body
+            ),
+            MergeError::Deserialize { path, message, .. } => {
+                write!(
+                    f,
+                    "Failed to deserialize response at `{}`: {}",
+                    path, message
+                )
+            }
+            MergeError::Serialize(err) => write!(f, "Failed to serialize request body: {}", err),
+            MergeError::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } => write!(
+                f,
+                "Request was rate limited, retry after {}s",
+                retry_after.as_secs()
+            ),
+            MergeError::RateLimited { .. } => write!(f, "Request was rate limited"),
+            MergeError::MissingConfiguration => {
+                write!(f, "No configuration was set on the request or the client")
+            }
+            MergeError::Io(err) => write!(f, "{}", err),
+            MergeError::AsyncPassthroughPending { receipt_id } => write!(
+                f,
+                "Async passthrough {} did not finish while polling",
+                receipt_id
+            ),
+        }
+    }
+}
+
+impl Error for MergeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MergeError::Transport(err) => Some(err),
+            MergeError::Serialize(err) => Some(err),
+            MergeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for MergeError {
+    fn from(err: reqwest::Error) -> Self {
+        MergeError::Transport(err)
+    }
+}
+
+impl From<std::io::Error> for MergeError {
+    fn from(err: std::io::Error) -> Self {
+        MergeError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Model {
+        id: String,
+        nested: Nested,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Nested {
+        count: i32,
+    }
+
+    #[test]
+    fn it_records_the_path_of_a_bad_field() {
+        let body = "{\"id\": \"1234\", \"nested\": {\"count\": \"many\"}}".to_string();
+        let err = MergeError::deserialize::<Model>(body.clone()).unwrap_err();
+        match err {
+            MergeError::Deserialize {
+                body: raw,
+                path,
+                message,
+            } => {
+                assert_eq!(raw, body);
+                assert_eq!(path, "nested.count");
+                assert!(message.contains("invalid type"));
+            }
+            other => panic!("Expected a deserialize error, got {:?}", other),
+        }
+    }
+}
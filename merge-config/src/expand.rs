@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+/// A related model, sent as its id unless the request listed the relation in `expand`, in
+/// which case Merge nests the whole model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Expandable<T> {
+    Id(String),
+    Expanded(Box<T>),
+}
+
+impl<T> Expandable<T> {
+    pub fn is_expanded(&self) -> bool {
+        matches!(self, Expandable::Expanded(_))
+    }
+
+    /// The id, when the relation was not expanded. Read it from the model otherwise.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Expandable::Id(id) => Some(id),
+            Expandable::Expanded(_) => None,
+        }
+    }
+
+    /// The related model, when the relation was expanded.
+    pub fn expanded(&self) -> Option<&T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Expanded(model) => Some(model),
+        }
+    }
+
+    pub fn into_expanded(self) -> Option<T> {
+        match self {
+            Expandable::Id(_) => None,
+            Expandable::Expanded(model) => Some(*model),
+        }
+    }
+}
+
+impl<T> From<String> for Expandable<T> {
+    fn from(id: String) -> Self {
+        Expandable::Id(id)
+    }
+}
+
+impl<T> From<&str> for Expandable<T> {
+    fn from(id: &str) -> Self {
+        Expandable::Id(id.to_string())
+    }
+}
+
+/// Declares the relations of a model that can be passed to `expand`, each with the name Merge
+/// uses for it. The enum is sent comma separated when used as `Vec<_>`.
+#[macro_export]
+macro_rules! expand_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident => $value:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value),*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl $crate::query::QueryValue for $name {
+            fn to_query_value(&self) -> String {
+                self.as_str().to_string()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::QueryValue;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    struct Team {
+        id: String,
+        name: String,
+    }
+
+    expand_enum! {
+        enum TeamExpand {
+            ParentTeam => "parent_team",
+            Members => "members",
+        }
+    }
+
+    #[test]
+    fn it_reads_an_id() {
+        let team: Expandable<Team> = serde_json::from_str("\"a1b2\"").unwrap();
+
+        assert!(!team.is_expanded());
+        assert_eq!(team.id(), Some("a1b2"));
+        assert_eq!(team.expanded(), None);
+        assert_eq!(team, Expandable::from("a1b2"));
+    }
+
+    #[test]
+    fn it_reads_an_expanded_model() {
+        let team: Expandable<Team> =
+            serde_json::from_str("{\"id\": \"a1b2\", \"name\": \"Platform\"}").unwrap();
+
+        assert!(team.is_expanded());
+        assert_eq!(team.id(), None);
+        assert_eq!(
+            team.expanded().map(|team| team.name.as_str()),
+            Some("Platform")
+        );
+        assert_eq!(
+            serde_json::to_value(&team).unwrap(),
+            serde_json::json!({"id": "a1b2", "name": "Platform"})
+        );
+    }
+
+    #[test]
+    fn it_joins_expanded_relations() {
+        let expand = vec![TeamExpand::ParentTeam, TeamExpand::Members];
+        assert_eq!(expand.to_query_value(), "parent_team,members");
+        assert_eq!(TeamExpand::Members.to_string(), "members");
+    }
+}
@@ -1 +1,33 @@
+#[macro_use]
+extern crate derive_builder;
+
+// Lets the request macros, which name `merge_config::...`, be used inside this crate too.
+extern crate self as merge_config;
+
+pub mod account;
+
+pub mod client;
+
 pub mod configuration;
+
+pub mod download;
+
+pub mod error;
+
+pub mod expand;
+
+pub mod link;
+
+pub mod pagination;
+
+pub mod passthrough;
+
+pub mod query;
+
+pub mod remote;
+
+pub mod retry;
+
+pub mod upload;
+
+pub mod write;
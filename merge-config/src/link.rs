@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::send_request;
+
+use crate::account::{AccountIntegration, Category};
+use crate::client::{MergeClient, MergeRequest, Method};
+use crate::configuration::Configuration;
+use crate::error::MergeError;
+
+/// Limits what a linked account syncs for one model.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CommonModelScope {
+    /// e.g. `hris.Employee`.
+    pub model_id: String,
+    /// e.g. `READ` and `WRITE`.
+    #[serde(default)]
+    #[builder(default)]
+    pub enabled_actions: Vec<String>,
+    #[serde(default)]
+    #[builder(default)]
+    pub disabled_fields: Vec<String>,
+}
+
+/// The end user Merge Link is opened for.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct LinkTokenRequest {
+    /// Your unique id for the end user's organization.
+    pub end_user_origin_id: String,
+    pub end_user_organization_name: String,
+    pub end_user_email_address: String,
+    #[builder(default)]
+    pub categories: Vec<Category>,
+    /// Skips the integration picker and opens Link for this integration's slug.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration: Option<String>,
+    /// Also returns a url the end user can open without embedding Link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub should_create_magic_link_url: Option<bool>,
+    /// How long the link token is valid for, 30 minutes by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub link_expiry_mins: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub common_models: Option<Vec<CommonModelScope>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct LinkTokenModel {
+    /// Passed to Merge Link in the frontend.
+    pub link_token: String,
+    #[builder(setter(into, strip_option), default)]
+    pub integration_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub magic_link_url: Option<String>,
+}
+
+/// Creates the link token Merge Link is opened with. Only the configuration's api key is used.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+pub struct CreateLinkTokenRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub body: LinkTokenRequest,
+}
+
+// The link endpoints are not versioned like the category APIs, so the path is written out here
+// rather than generated by `send_request`.
+impl MergeRequest for CreateLinkTokenRequest {
+    type Response = LinkTokenModel;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/api/integrations/create-link-token".to_string()
+    }
+
+    fn body(&self) -> Result<Option<serde_json::Value>, MergeError> {
+        serde_json::to_value(&self.body)
+            .map(Some)
+            .map_err(MergeError::Serialize)
+    }
+
+    fn configuration(&self) -> Option<&Configuration> {
+        self.config.as_ref()
+    }
+}
+
+impl CreateLinkTokenRequest {
+    pub async fn send_request(&self) -> Result<LinkTokenModel, MergeError> {
+        match &self.config {
+            Some(config) => MergeClient::new(config.clone()).send(self).await,
+            None => Err(MergeError::MissingConfiguration),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AccountTokenModel {
+    pub account_token: String,
+    #[builder(setter(into, strip_option), default)]
+    pub integration: Option<AccountIntegration>,
+}
+
+impl AccountTokenModel {
+    /// `config` with the account token as its access token, for the new linked account.
+    pub fn configuration(&self, config: &Configuration) -> Configuration {
+        Configuration {
+            access_token: self.account_token.clone(),
+            ..config.clone()
+        }
+    }
+}
+
+/// Exchanges the `public_token` Merge Link hands to the frontend for the linked account's
+/// account token.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="account-token/{public_token}", return_type=AccountTokenModel)]
+pub struct AccountTokenRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    pub public_token: String,
+}
+
+impl AccountTokenRequest {
+    /// Sends the exchange and returns the request's configuration carrying the new account
+    /// token, ready to use for the linked account.
+    pub async fn exchange(&self) -> Result<Configuration, MergeError> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or(MergeError::MissingConfiguration)?;
+        Ok(self.send_request().await?.configuration(config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_create_a_link_token() {
+        let m = mock("POST", "/api/integrations/create-link-token")
+            .match_header("authorization", "Bearer someKey")
+            .match_body(Matcher::Json(serde_json::json!({
+                "end_user_origin_id": "12345",
+                "end_user_organization_name": "Waystar Royco",
+                "end_user_email_address": "hello@merge.dev",
+                "categories": ["hris", "filestorage"],
+                "should_create_magic_link_url": true,
+                "link_expiry_mins": 60,
+                "common_models": [{
+                    "model_id": "hris.Employee",
+                    "enabled_actions": ["READ"],
+                    "disabled_fields": ["ssn"]
+                }]
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"link_token\": \"m7sN9Ssn2QJa-jwFhAkhJh6QqhkPu6aAu0iCy8X9V4mCwAh_0lc4MXA\",
+                  \"integration_name\": null,
+                  \"magic_link_url\": \"https://link.merge.dev/magic/m7sN9Ssn2QJa\"
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = Configuration::new("someKey", "").with_base_url(mockito::server_url());
+
+        let body: LinkTokenRequest = LinkTokenRequestBuilder::default()
+            .end_user_origin_id("12345")
+            .end_user_organization_name("Waystar Royco")
+            .end_user_email_address("hello@merge.dev")
+            .categories(vec![Category::Hris, Category::FileStorage])
+            .should_create_magic_link_url(true)
+            .link_expiry_mins(60u32)
+            .common_models(vec![CommonModelScopeBuilder::default()
+                .model_id("hris.Employee")
+                .enabled_actions(vec!["READ".to_string()])
+                .disabled_fields(vec!["ssn".to_string()])
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let request = CreateLinkTokenRequestBuilder::default()
+            .config(config)
+            .body(body)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.link_token,
+            "m7sN9Ssn2QJa-jwFhAkhJh6QqhkPu6aAu0iCy8X9V4mCwAh_0lc4MXA"
+        );
+        assert_eq!(response.integration_name, None);
+        assert!(response.magic_link_url.is_some());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_exchange_a_public_token() {
+        let m = mock("GET", "/api/ats/v1/account-token/somePublicToken")
+            .match_header("authorization", "Bearer someKey")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"account_token\": \"someAccountToken\",
+                  \"integration\": {
+                    \"name\": \"Greenhouse\",
+                    \"categories\": [\"ats\"],
+                    \"slug\": \"greenhouse\"
+                  }
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = Configuration::new("someKey", "").with_base_url(mockito::server_url());
+
+        let request = AccountTokenRequestBuilder::default()
+            .config(config)
+            .category(Category::Ats)
+            .public_token("somePublicToken")
+            .build()
+            .unwrap();
+
+        let config = request.exchange().await.unwrap();
+
+        assert_eq!(config.api_key, "someKey");
+        assert_eq!(config.access_token, "someAccountToken");
+        assert_eq!(config.base_url, mockito::server_url());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_encodes_the_public_token_in_the_path() {
+        let m = mock("GET", "/api/hris/v1/account-token/a%2Fb%2Bc%3Fd")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{\"account_token\": \"someAccountToken\"}")
+            .expect(1)
+            .create();
+
+        let config = Configuration::new("someKey", "").with_base_url(mockito::server_url());
+
+        let request = AccountTokenRequestBuilder::default()
+            .config(config)
+            .category(Category::Hris)
+            .public_token("a/b+c?d")
+            .build()
+            .unwrap();
+
+        let config = request.exchange().await.unwrap();
+
+        assert_eq!(config.access_token, "someAccountToken");
+        m.assert()
+    }
+}
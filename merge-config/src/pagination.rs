@@ -0,0 +1,134 @@
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{MergeClient, MergeRequest};
+use crate::error::MergeError;
+
+/// A page of results from any of Merge's list endpoints.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    pub next: Option<String>,
+    pub previous: Option<String>,
+    pub results: Vec<T>,
+}
+
+/// A list request that can be walked page by page.
+///
+/// Implemented by the `send_request` attribute macro for every request whose return type is
+/// `Paginated<T>`.
+pub trait ListRequest<T>: MergeRequest<Response = Paginated<T>> + Clone {
+    /// Points the request at the page behind `cursor`, leaving every other filter untouched.
+    fn set_cursor(&mut self, cursor: String);
+}
+
+/// Lazily follows the `next` cursor of a list request. Built with `MergeClient::paginate`.
+#[derive(Debug, Clone)]
+pub struct Paginator<R> {
+    client: MergeClient,
+    request: R,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl<R> Paginator<R> {
+    pub fn new(client: MergeClient, request: R) -> Self {
+        Paginator {
+            client,
+            request,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    /// Stops after `max_pages` pages have been fetched.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Stops once `max_items` models have been yielded, truncating the last page if needed.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Yields every page, following `next` until it is empty, repeats the cursor of the page
+    /// just fetched, or a limit is reached.
+    pub fn pages<T>(self) -> impl Stream<Item = Result<Paginated<T>, MergeError>>
+    where
+        R: ListRequest<T>,
+        T: DeserializeOwned,
+    {
+        let state = PageState {
+            paginator: self,
+            pages: 0,
+            items: 0,
+            cursor: None,
+            done: false,
+        };
+
+        stream::try_unfold(state, |mut state| async move {
+            if state.is_exhausted() {
+                return Ok(None);
+            }
+
+            let mut page = state
+                .paginator
+                .client
+                .send(&state.paginator.request)
+                .await?;
+            state.pages += 1;
+
+            if let Some(max_items) = state.paginator.max_items {
+                page.results.truncate(max_items - state.items);
+            }
+            state.items += page.results.len();
+
+            // A `next` that points back at the page just fetched would be followed forever.
+            match &page.next {
+                Some(next) if !next.is_empty() && state.cursor.as_ref() != Some(next) => {
+                    state.cursor = Some(next.clone());
+                    state.paginator.request.set_cursor(next.clone());
+                }
+                _ => state.done = true,
+            }
+
+            Ok(Some((page, state)))
+        })
+    }
+
+    /// Yields every model across all pages.
+    pub fn items<T>(self) -> impl Stream<Item = Result<T, MergeError>>
+    where
+        R: ListRequest<T>,
+        T: DeserializeOwned,
+    {
+        self.pages()
+            .map_ok(|page| stream::iter(page.results.into_iter().map(Ok)))
+            .try_flatten()
+    }
+}
+
+struct PageState<R> {
+    paginator: Paginator<R>,
+    pages: usize,
+    items: usize,
+    /// The cursor the current request points at, `None` for the first page.
+    cursor: Option<String>,
+    done: bool,
+}
+
+impl<R> PageState<R> {
+    fn is_exhausted(&self) -> bool {
+        self.done
+            || self
+                .paginator
+                .max_pages
+                .is_some_and(|max_pages| self.pages >= max_pages)
+            || self
+                .paginator
+                .max_items
+                .is_some_and(|max_items| self.items >= max_items)
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::send_request;
+
+use crate::account::Category;
+use crate::client::MergeClient;
+use crate::configuration::Configuration;
+use crate::error::MergeError;
+
+/// How `data` is sent to the third party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RequestFormat {
+    Json,
+    Xml,
+    Multipart,
+}
+
+/// One field of a `MULTIPART` passthrough.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct MultipartFormField {
+    pub name: String,
+    pub data: String,
+    /// How `data` is encoded: `RAW`, `BASE64` or `GZIP_BASE64`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub encoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub content_type: Option<String>,
+}
+
+/// The request Merge makes to the third party on your behalf, authenticated as the linked
+/// account.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct DataPassthrough {
+    /// e.g. `GET` or `POST`.
+    pub method: String,
+    /// The path below the integration's base url, e.g. `/scooters`.
+    pub path: String,
+    /// Replaces the integration's base url, for third parties with several APIs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub base_url_override: Option<String>,
+    /// The raw body, in `request_format`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub multipart_form_data: Option<Vec<MultipartFormField>>,
+    /// Sent to the third party in addition to the authentication headers Merge adds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub headers: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub request_format: Option<RequestFormat>,
+    /// Returns XML responses converted to JSON.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub normalize_response: Option<bool>,
+}
+
+impl DataPassthroughBuilder {
+    /// Sends `data` as a JSON body.
+    pub fn json<T>(&mut self, data: &T) -> Result<&mut Self, serde_json::Error>
+    where
+        T: Serialize,
+    {
+        self.data(serde_json::to_string(data)?);
+        Ok(self.request_format(RequestFormat::Json))
+    }
+}
+
+/// What the third party answered.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct RemoteResponse {
+    pub method: String,
+    pub path: String,
+    /// The status the third party answered with, not the status of the call to Merge.
+    pub status: u16,
+    #[serde(default)]
+    #[builder(default)]
+    pub response: serde_json::Value,
+    #[builder(setter(into, strip_option), default)]
+    pub response_headers: Option<BTreeMap<String, serde_json::Value>>,
+    /// `JSON`, or `BASE64_GZIP` for binary responses.
+    #[builder(setter(into, strip_option), default)]
+    pub response_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub headers: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Calls the third party directly, for data the common models do not cover.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="passthrough", return_type=RemoteResponse, method="POST")]
+pub struct PassthroughRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    pub body: DataPassthrough,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AsyncPassthroughReceipt {
+    pub async_passthrough_receipt_id: String,
+}
+
+/// Queues a passthrough for third parties too slow to answer within a single request. Use
+/// `send_and_wait` to poll for the answer.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="async-passthrough", return_type=AsyncPassthroughReceipt, method="POST")]
+pub struct AsyncPassthroughRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    pub body: DataPassthrough,
+}
+
+/// The answer to an async passthrough, or whatever Merge returns while it is still running.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AsyncPassthroughResult {
+    Ready(Box<RemoteResponse>),
+    Pending(serde_json::Value),
+}
+
+/// Fetches the result of an async passthrough once, `id` being the receipt id.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="{category}", model="async-passthrough", return_type=AsyncPassthroughResult)]
+pub struct AsyncPassthroughResultRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<Configuration>,
+    pub category: Category,
+    pub id: String,
+}
+
+/// How often, and for how long, the result of an async passthrough is polled.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+pub struct PollPolicy {
+    /// The wait before every poll, including the first one.
+    #[builder(default = "Duration::from_secs(1)")]
+    pub interval: Duration,
+    #[builder(default = "30")]
+    pub max_polls: u32,
+}
+
+impl Default for PollPolicy {
+    fn default() -> Self {
+        PollPolicyBuilder::default()
+            .build()
+            .expect("every poll policy field has a default")
+    }
+}
+
+impl AsyncPassthroughReceipt {
+    /// Polls through `client` until the third party's answer is available.
+    pub async fn wait(
+        &self,
+        client: &MergeClient,
+        category: Category,
+        poll: &PollPolicy,
+    ) -> Result<RemoteResponse, MergeError> {
+        let request = AsyncPassthroughResultRequest {
+            config: None,
+            category,
+            id: self.async_passthrough_receipt_id.clone(),
+        };
+        for _ in 0..poll.max_polls {
+            tokio::time::sleep(poll.interval).await;
+            if let AsyncPassthroughResult::Ready(response) = client.send(&request).await? {
+                return Ok(*response);
+            }
+        }
+        Err(MergeError::AsyncPassthroughPending {
+            receipt_id: self.async_passthrough_receipt_id.clone(),
+        })
+    }
+}
+
+impl AsyncPassthroughRequest {
+    /// Queues the passthrough and resolves once its answer is available, see
+    /// `AsyncPassthroughReceipt::wait`.
+    pub async fn send_and_wait(&self, poll: &PollPolicy) -> Result<RemoteResponse, MergeError> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or(MergeError::MissingConfiguration)?;
+        let client = MergeClient::new(config.clone());
+        let receipt = client.send(self).await?;
+        receipt.wait(&client, self.category, poll).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    fn config() -> Configuration {
+        Configuration::new("test", "test").with_base_url(mockito::server_url())
+    }
+
+    fn poll() -> PollPolicy {
+        PollPolicyBuilder::default()
+            .interval(Duration::from_millis(1))
+            .max_polls(2u32)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_a_request_through() {
+        let m = mock("POST", "/api/hris/v1/passthrough")
+            .match_body(Matcher::Json(serde_json::json!({
+                "method": "POST",
+                "path": "/scooters",
+                "data": "{\"company\":\"Lime\",\"model\":\"Gen 2.5\"}",
+                "headers": {"EXTRA-HEADER": "value"},
+                "request_format": "JSON",
+                "normalize_response": true
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"method\": \"POST\",
+                  \"path\": \"/scooters\",
+                  \"status\": 201,
+                  \"response\": {\"scooters\": [{\"company\": \"Lime\", \"model\": \"Gen 2.5\"}]},
+                  \"response_headers\": {\"X-Page-Token\": \"value\"},
+                  \"response_type\": \"JSON\",
+                  \"headers\": {\"EXTRA-HEADER\": \"value\"}
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let body = DataPassthroughBuilder::default()
+            .method("POST")
+            .path("/scooters")
+            .json(&serde_json::json!({"company": "Lime", "model": "Gen 2.5"}))
+            .unwrap()
+            .headers(BTreeMap::from([(
+                "EXTRA-HEADER".to_string(),
+                "value".to_string(),
+            )]))
+            .normalize_response(true)
+            .build()
+            .unwrap();
+
+        let request = PassthroughRequestBuilder::default()
+            .config(config())
+            .category(Category::Hris)
+            .body(body)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.status, 201);
+        assert_eq!(response.response["scooters"][0]["company"], "Lime");
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_wait_for_an_async_passthrough() {
+        let queued = mock("POST", "/api/ats/v1/async-passthrough")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{\"async_passthrough_receipt_id\": \"8a3f4c1e\"}")
+            .expect(1)
+            .create();
+        let ready = mock("GET", "/api/ats/v1/async-passthrough/8a3f4c1e")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"method\": \"GET\",
+                  \"path\": \"/candidates/1/scorecards\",
+                  \"status\": 200,
+                  \"response\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let body = DataPassthroughBuilder::default()
+            .method("GET")
+            .path("/candidates/1/scorecards")
+            .build()
+            .unwrap();
+
+        let request = AsyncPassthroughRequestBuilder::default()
+            .config(config())
+            .category(Category::Ats)
+            .body(body)
+            .build()
+            .unwrap();
+
+        let response = request.send_and_wait(&poll()).await.unwrap();
+
+        assert_eq!(response.path, "/candidates/1/scorecards");
+        assert_eq!(response.response, serde_json::json!([]));
+        queued.assert();
+        ready.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_give_up_on_a_pending_async_passthrough() {
+        let pending = mock("GET", "/api/crm/v1/async-passthrough/5d0e2b7a")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{\"status\": \"PENDING\"}")
+            .expect(2)
+            .create();
+
+        let receipt = AsyncPassthroughReceiptBuilder::default()
+            .async_passthrough_receipt_id("5d0e2b7a")
+            .build()
+            .unwrap();
+
+        let err = receipt
+            .wait(&MergeClient::new(config()), Category::Crm, &poll())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            MergeError::AsyncPassthroughPending { receipt_id } if receipt_id == "5d0e2b7a"
+        ));
+        pending.assert()
+    }
+}
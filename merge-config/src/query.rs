@@ -0,0 +1,190 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, TimeZone};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Everything but the RFC 3986 unreserved characters, with `,` left as is so comma separated
+/// lists stay readable.
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b',');
+
+/// Everything but the RFC 3986 unreserved characters, so a value can never end the path segment
+/// it is placed in.
+const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A value that can be used as a query parameter by `generate_url_params`.
+pub trait QueryValue {
+    fn to_query_value(&self) -> String;
+}
+
+macro_rules! display_query_value {
+    ($($ty:ty),*) => {
+        $(
+            impl QueryValue for $ty {
+                fn to_query_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+display_query_value!(String, str, bool, i32, i64, u32, u64, f64);
+
+impl<T> QueryValue for &T
+where
+    T: QueryValue + ?Sized,
+{
+    fn to_query_value(&self) -> String {
+        (**self).to_query_value()
+    }
+}
+
+/// Lists are sent comma separated. Mark the field `#[param(repeated)]` to repeat the key instead.
+impl<T> QueryValue for Vec<T>
+where
+    T: QueryValue,
+{
+    fn to_query_value(&self) -> String {
+        self.iter()
+            .map(QueryValue::to_query_value)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl<Tz> QueryValue for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn to_query_value(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+impl QueryValue for NaiveDate {
+    fn to_query_value(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+/// Builds `?key=value&...` with every key and value percent-encoded, or an empty string when
+/// there are no pairs.
+pub fn encode_query<K, V>(pairs: &[(K, V)]) -> String
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    if pairs.is_empty() {
+        return "".to_string();
+    }
+    let query = pairs
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                utf8_percent_encode(key.as_ref(), QUERY_ENCODE_SET),
+                utf8_percent_encode(value.as_ref(), QUERY_ENCODE_SET)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("?{}", query)
+}
+
+/// Percent-encodes `value` for use as a single path segment, e.g. an id placed in a path by
+/// `send_request`.
+pub fn encode_path_segment<T>(value: &T) -> String
+where
+    T: Display + ?Sized,
+{
+    utf8_percent_encode(&value.to_string(), PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+    use merge_proc_macros::generate_url_params;
+
+    #[test]
+    fn it_encodes_reserved_characters() {
+        let query = encode_query(&[
+            ("bank_name", "Wells Fargo & Co"),
+            ("cursor", "cD0yMDIx+LTAx=="),
+        ]);
+        assert_eq!(
+            query,
+            "?bank_name=Wells%20Fargo%20%26%20Co&cursor=cD0yMDIx%2BLTAx%3D%3D"
+        );
+    }
+
+    #[test]
+    fn it_encodes_path_segments() {
+        assert_eq!(encode_path_segment("a/b c?d#e%f"), "a%2Fb%20c%3Fd%23e%25f");
+        assert_eq!(
+            encode_path_segment("fd1e0fb5-8f92-4ec9-9f32-179cf732867d"),
+            "fd1e0fb5-8f92-4ec9-9f32-179cf732867d"
+        );
+    }
+
+    #[test]
+    fn it_returns_nothing_without_pairs() {
+        assert_eq!(encode_query::<&str, &str>(&[]), "");
+    }
+
+    #[test]
+    fn it_joins_lists_with_commas() {
+        let expand = vec!["employee".to_string(), "company".to_string()];
+        assert_eq!(expand.to_query_value(), "employee,company");
+    }
+
+    #[test]
+    fn it_renames_and_repeats_params() {
+        #[generate_url_params]
+        #[derive(Default)]
+        struct Params {
+            #[param(rename = "type")]
+            kind: Option<String>,
+            r#ref: Option<String>,
+            ids: Option<Vec<String>>,
+            #[param(repeated)]
+            tags: Option<Vec<String>>,
+        }
+
+        let params = Params {
+            kind: Some("MEDICAL".to_string()),
+            r#ref: Some("abc".to_string()),
+            ids: Some(vec!["1".to_string(), "2".to_string()]),
+            tags: Some(vec!["a".to_string(), "b".to_string()]),
+        };
+
+        assert_eq!(
+            params.generate_url_params(),
+            "?type=MEDICAL&ref=abc&ids=1,2&tags=a&tags=b"
+        );
+    }
+
+    #[test]
+    fn it_formats_dates_as_rfc_3339() {
+        let date = Utc.with_ymd_and_hms(2021, 12, 6, 10, 11, 26).unwrap();
+        assert_eq!(date.to_query_value(), "2021-12-06T10:11:26Z");
+
+        let offset = FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2021, 12, 6, 10, 11, 26)
+            .unwrap();
+        assert_eq!(offset.to_query_value(), "2021-12-06T10:11:26+01:00");
+
+        let day = NaiveDate::from_ymd_opt(2021, 12, 6).unwrap();
+        assert_eq!(day.to_query_value(), "2021-12-06");
+    }
+}
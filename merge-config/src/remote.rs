@@ -0,0 +1,212 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A raw response from the third party, returned with `include_remote_data=true`. What `data`
+/// holds depends entirely on the integration.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct RemoteData {
+    /// The third party endpoint `data` was read from.
+    #[builder(setter(into, strip_option), default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    #[builder(setter(into), default)]
+    pub data: Value,
+}
+
+impl RemoteData {
+    /// The entry read from the third party endpoint `path`.
+    pub fn find<'a>(remote_data: &'a [RemoteData], path: &str) -> Option<&'a RemoteData> {
+        remote_data
+            .iter()
+            .find(|remote_data| remote_data.path.as_deref() == Some(path))
+    }
+
+    /// The value at `path` in `data`. Keys and array indices are separated by dots, e.g.
+    /// `compensation.0.rate`; a path starting with `/` is read as a JSON pointer instead.
+    pub fn value(&self, path: &str) -> Option<&Value> {
+        if path.starts_with('/') {
+            return self.data.pointer(path);
+        }
+        path.split('.')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(&self.data, |value, segment| match value {
+                Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+                value => value.get(segment),
+            })
+    }
+
+    /// The value at `path`, see `value`, decoded as `T`. `None` when it is missing or has
+    /// another type.
+    pub fn get<T>(&self, path: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(self.value(path)?).ok()
+    }
+}
+
+/// Describes a custom field of the third party, returned with `include_remote_fields=true`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct RemoteFieldClass {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub display_name: Option<String>,
+    /// The name of the field in the third party's API.
+    #[builder(setter(into, strip_option), default)]
+    pub remote_key_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_custom: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_required: Option<bool>,
+    /// e.g. `string`, `number`, `date`, `bool`, `list` or `object`.
+    #[builder(setter(into, strip_option), default)]
+    pub field_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub field_format: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub field_choices: Option<Vec<Value>>,
+    #[builder(setter(into, strip_option), default)]
+    pub item_schema: Option<Value>,
+}
+
+/// The value of a third party custom field on a model.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct RemoteField {
+    /// The id of the field's `RemoteFieldClass`, or the class itself when Merge includes it.
+    #[builder(setter(into, strip_option), default)]
+    pub remote_field_class: Option<Value>,
+    #[builder(setter(into, strip_option), default)]
+    pub value: Option<Value>,
+}
+
+impl RemoteField {
+    /// The id of the field's class, whether Merge sent the id or the whole class.
+    pub fn class_id(&self) -> Option<&str> {
+        match self.remote_field_class.as_ref()? {
+            Value::String(id) => Some(id),
+            class => class.get("id")?.as_str(),
+        }
+    }
+
+    /// The field's class, when Merge sent it in full.
+    pub fn class(&self) -> Option<RemoteFieldClass> {
+        match self.remote_field_class.as_ref()? {
+            class @ Value::Object(_) => RemoteFieldClass::deserialize(class).ok(),
+            _ => None,
+        }
+    }
+
+    /// `value` decoded as `T`.
+    pub fn get<T>(&self) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(self.value.as_ref()?).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn remote_data() -> RemoteData {
+        serde_json::from_value(json!({
+            "path": "/employees/1234",
+            "data": {
+                "id": 1234,
+                "work": {"title": "Engineer", "manager": null},
+                "compensation": [{"rate": 55.5, "currency": "USD"}]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_reads_arbitrary_json() {
+        let legacy: RemoteData = serde_json::from_value(json!({
+            "path": "/bank-info",
+            "data": ["Varies by platform"]
+        }))
+        .unwrap();
+        assert_eq!(legacy.data, json!(["Varies by platform"]));
+
+        let remote_data = remote_data();
+        assert_eq!(remote_data.data["work"]["title"], "Engineer");
+    }
+
+    #[test]
+    fn it_reads_values_by_path() {
+        let remote_data = remote_data();
+
+        assert_eq!(remote_data.get::<i64>("id"), Some(1234));
+        assert_eq!(
+            remote_data.get::<String>("work.title"),
+            Some("Engineer".to_string())
+        );
+        assert_eq!(remote_data.get::<f64>("compensation.0.rate"), Some(55.5));
+        assert_eq!(
+            remote_data.get::<String>("/compensation/0/currency"),
+            Some("USD".to_string())
+        );
+        assert_eq!(remote_data.value("work.manager"), Some(&Value::Null));
+        assert_eq!(remote_data.value("compensation.1.rate"), None);
+        assert_eq!(remote_data.get::<i64>("work.title"), None);
+    }
+
+    #[test]
+    fn it_finds_remote_data_by_endpoint() {
+        let all = vec![
+            RemoteDataBuilder::default()
+                .path("/employees")
+                .build()
+                .unwrap(),
+            remote_data(),
+        ];
+
+        let found = RemoteData::find(&all, "/employees/1234").unwrap();
+        assert_eq!(found.get::<i64>("id"), Some(1234));
+        assert!(RemoteData::find(&all, "/teams").is_none());
+    }
+
+    #[test]
+    fn it_reads_remote_fields() {
+        let by_id: RemoteField = serde_json::from_value(json!({
+            "remote_field_class": "b0b4de12-7efc-4a04-99f5-8d8ce9e6e2b6",
+            "value": "Enterprise"
+        }))
+        .unwrap();
+        assert_eq!(
+            by_id.class_id(),
+            Some("b0b4de12-7efc-4a04-99f5-8d8ce9e6e2b6")
+        );
+        assert_eq!(by_id.class(), None);
+        assert_eq!(by_id.get::<String>(), Some("Enterprise".to_string()));
+
+        let expanded: RemoteField = serde_json::from_value(json!({
+            "remote_field_class": {
+                "id": "b0b4de12-7efc-4a04-99f5-8d8ce9e6e2b6",
+                "display_name": "Tier",
+                "remote_key_name": "tier__c",
+                "is_custom": true,
+                "field_type": "string"
+            },
+            "value": "Enterprise"
+        }))
+        .unwrap();
+        assert_eq!(
+            expanded.class_id(),
+            Some("b0b4de12-7efc-4a04-99f5-8d8ce9e6e2b6")
+        );
+        let class = expanded.class().unwrap();
+        assert_eq!(class.remote_key_name, Some("tier__c".to_string()));
+        assert_eq!(class.is_custom, Some(true));
+    }
+}
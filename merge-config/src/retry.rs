@@ -0,0 +1,267 @@
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::Method;
+
+use crate::error::MergeError;
+
+const RATE_LIMIT_LIMIT: &str = "x-ratelimit-limit";
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// How a `MergeClient` retries failed requests.
+///
+/// Only idempotent methods are retried unless `retry_non_idempotent` is set, so a `POST`
+/// that timed out is never sent twice by accident.
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    #[builder(default = "3")]
+    pub max_attempts: u32,
+    #[builder(default = "Duration::from_millis(500)")]
+    pub base_delay: Duration,
+    /// Upper bound for the computed backoff. A `Retry-After` longer than this is not waited
+    /// out; the error is returned instead.
+    #[builder(default = "Duration::from_secs(30)")]
+    pub max_delay: Duration,
+    #[builder(default = "true")]
+    pub jitter: bool,
+    #[builder(default = "vec![429, 500, 502, 503, 504]")]
+    pub retryable_statuses: Vec<u16>,
+    #[builder(default = "false")]
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicyBuilder::default()
+            .build()
+            .expect("every retry policy field has a default")
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Exponential backoff for the given attempt (1 based), capped at `max_delay`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// How long to wait before retrying `error`, or `None` when it should be returned.
+    pub fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        error: &MergeError,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(is_idempotent(method) || self.retry_non_idempotent) {
+            return None;
+        }
+
+        let requested = match error {
+            MergeError::Transport(err) if err.is_timeout() || err.is_connect() => None,
+            MergeError::RateLimited {
+                retry_after,
+                response,
+            } if self.retryable_statuses.contains(&response.status) => {
+                retry_after.or_else(|| RateLimit::from_headers(&response.headers).reset)
+            }
+            MergeError::Status(response) if self.retryable_statuses.contains(&response.status) => {
+                retry_after(&response.headers)
+            }
+            _ => return None,
+        };
+
+        match requested {
+            Some(delay) if delay > self.max_delay => None,
+            Some(delay) => Some(delay),
+            None => Some(self.backoff(attempt)),
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// The rate-limit headers of the most recent response.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    /// Time until the current window resets.
+    pub reset: Option<Duration>,
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        RateLimit {
+            limit: header_u64(headers, RATE_LIMIT_LIMIT),
+            remaining: header_u64(headers, RATE_LIMIT_REMAINING),
+            reset: header_u64(headers, RATE_LIMIT_RESET).map(Duration::from_secs),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.limit.is_none() && self.remaining.is_none() && self.reset.is_none()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Reads `Retry-After`, given either as seconds or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => httpdate::parse_http_date(value)
+            .ok()?
+            .duration_since(SystemTime::now())
+            .ok()
+            .or(Some(Duration::ZERO)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorResponse;
+
+    fn status_error(status: u16, headers: HeaderMap) -> MergeError {
+        MergeError::Status(Box::new(ErrorResponse {
+            status,
+            body: None,
+            raw_body: "".to_string(),
+            headers,
+        }))
+    }
+
+    #[test]
+    fn it_backs_off_exponentially_up_to_the_max_delay() {
+        let policy = RetryPolicyBuilder::default()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(350))
+            .jitter(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn it_keeps_jitter_within_half_the_delay() {
+        let policy = RetryPolicyBuilder::default()
+            .base_delay(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        for _ in 0..100 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn it_only_retries_idempotent_methods_by_default() {
+        let policy = RetryPolicyBuilder::default().jitter(false).build().unwrap();
+        let error = status_error(503, HeaderMap::new());
+
+        assert_eq!(
+            policy.retry_delay(1, &Method::GET, &error),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(policy.retry_delay(1, &Method::POST, &error), None);
+        assert_eq!(policy.retry_delay(3, &Method::GET, &error), None);
+
+        let policy = RetryPolicyBuilder::default()
+            .retry_non_idempotent(true)
+            .build()
+            .unwrap();
+        assert!(policy.retry_delay(1, &Method::POST, &error).is_some());
+    }
+
+    #[test]
+    fn it_does_not_retry_client_errors() {
+        let policy = RetryPolicy::default();
+        let error = status_error(404, HeaderMap::new());
+        assert_eq!(policy.retry_delay(1, &Method::GET, &error), None);
+    }
+
+    #[test]
+    fn it_honours_retry_after() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+
+        assert_eq!(
+            policy.retry_delay(1, &Method::GET, &status_error(503, headers.clone())),
+            Some(Duration::from_secs(7))
+        );
+
+        headers.insert(RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(
+            policy.retry_delay(1, &Method::GET, &status_error(503, headers)),
+            None
+        );
+    }
+
+    #[test]
+    fn it_reads_retry_after_dates() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "30".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(30)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn it_reads_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_LIMIT, "100".parse().unwrap());
+        headers.insert(RATE_LIMIT_REMAINING, "0".parse().unwrap());
+        headers.insert(RATE_LIMIT_RESET, "42".parse().unwrap());
+
+        let rate_limit = RateLimit::from_headers(&headers);
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(0));
+        assert_eq!(rate_limit.reset, Some(Duration::from_secs(42)));
+        assert!(rate_limit.is_exhausted());
+        assert!(RateLimit::from_headers(&HeaderMap::new()).is_empty());
+    }
+}
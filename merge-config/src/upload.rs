@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use bytes::Bytes;
+use futures::{future, stream, StreamExt, TryStream, TryStreamExt};
+use reqwest::multipart::Part;
+use reqwest::Body;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+pub use reqwest::multipart::Form;
+
+/// The content of a file sent as the `file` part of a multipart upload.
+///
+/// Buffered content is reference counted, so the request can be cloned, and retried, without
+/// copying the file. Streamed content is never held in memory as a whole.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FileUpload {
+    pub file_name: String,
+    pub content: UploadContent,
+    /// Sent as the part's `Content-Type`, `application/octet-stream` when unset.
+    pub mime_type: Option<String>,
+}
+
+/// Where the bytes of a [`FileUpload`] come from.
+#[derive(Clone)]
+pub enum UploadContent {
+    Bytes(Bytes),
+    /// Opened again for every attempt, so a retried upload starts from the beginning. `length`
+    /// is sent as the part's `Content-Length` and must match what the stream yields.
+    Stream {
+        length: u64,
+        open: Arc<dyn Fn() -> Body + Send + Sync>,
+    },
+}
+
+impl Default for UploadContent {
+    fn default() -> Self {
+        UploadContent::Bytes(Bytes::new())
+    }
+}
+
+impl fmt::Debug for UploadContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UploadContent::Bytes(bytes) => f.debug_tuple("Bytes").field(bytes).finish(),
+            UploadContent::Stream { length, .. } => f
+                .debug_struct("Stream")
+                .field("length", length)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+// Streams cannot be compared by content, two are equal only when they share the same source.
+impl PartialEq for UploadContent {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (UploadContent::Bytes(a), UploadContent::Bytes(b)) => a == b,
+            (
+                UploadContent::Stream { length: a, open: f },
+                UploadContent::Stream { length: b, open: g },
+            ) => a == b && Arc::ptr_eq(f, g),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for UploadContent {}
+
+impl FileUpload {
+    pub fn new<N, C>(file_name: N, content: C) -> Self
+    where
+        N: Into<String>,
+        C: Into<Bytes>,
+    {
+        FileUpload {
+            file_name: file_name.into(),
+            content: UploadContent::Bytes(content.into()),
+            mime_type: None,
+        }
+    }
+
+    /// Streams the content returned by `open`, which is called once per attempt. `length` is
+    /// the size of the content in bytes.
+    pub fn from_stream<N, F, S>(file_name: N, length: u64, open: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn() -> S + Send + Sync + 'static,
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn Error + Send + Sync>>,
+        Bytes: From<S::Ok>,
+    {
+        FileUpload {
+            file_name: file_name.into(),
+            content: UploadContent::Stream {
+                length,
+                open: Arc::new(move || Body::wrap_stream(open())),
+            },
+            mime_type: None,
+        }
+    }
+
+    /// Streams the file at `path`, named after its last component. The file is read when the
+    /// request is sent, and reopened on retries.
+    pub async fn from_path<P>(path: P) -> std::io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path: PathBuf = path.as_ref().to_path_buf();
+        let length = tokio::fs::metadata(&path).await?.len();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(FileUpload::from_stream(file_name, length, move || {
+            stream::once(tokio::fs::File::open(path.clone()))
+                .map_ok(ReaderStream::new)
+                .try_flatten()
+        }))
+    }
+
+    /// Streams `length` bytes from `reader`. A reader can only be read once, so an attempt
+    /// retried after it was consumed fails; use `from_path` or `from_stream` where retries matter.
+    pub fn from_reader<N, R>(file_name: N, length: u64, reader: R) -> Self
+    where
+        N: Into<String>,
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        let reader = Arc::new(Mutex::new(Some(reader)));
+        FileUpload::from_stream(file_name, length, move || {
+            match reader.lock().unwrap_or_else(PoisonError::into_inner).take() {
+                Some(reader) => ReaderStream::new(reader).left_stream(),
+                None => stream::once(future::ready(Err(std::io::Error::other(
+                    "the upload reader was consumed by an earlier attempt",
+                ))))
+                .right_stream(),
+            }
+        })
+    }
+
+    pub fn with_mime_type<M>(mut self, mime_type: M) -> Self
+    where
+        M: Into<String>,
+    {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// A fresh part for every attempt, since a form is consumed when it is sent.
+    pub fn part(&self) -> Part {
+        let part = match &self.content {
+            UploadContent::Bytes(bytes) => Part::stream(bytes.clone()),
+            UploadContent::Stream { length, open } => Part::stream_with_length(open(), *length),
+        }
+        .file_name(self.file_name.clone());
+        match &self.mime_type {
+            // An unparsable mime type falls back to the default rather than failing the upload.
+            // It is checked on an empty part, so a stream is never opened for nothing.
+            Some(mime_type) if Part::text("").mime_str(mime_type).is_ok() => part
+                .mime_str(mime_type)
+                .expect("the mime type was parsed above"),
+            _ => part,
+        }
+    }
+}
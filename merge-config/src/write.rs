@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// Where in the submitted model a validation problem was found.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ValidationProblemSource {
+    pub pointer: String,
+}
+
+/// A problem Merge or the third party found with a submitted model.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ValidationProblem {
+    #[builder(setter(into, strip_option), default)]
+    pub source: Option<ValidationProblemSource>,
+    pub title: String,
+    pub detail: String,
+    pub problem_type: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct DebugModelLogSummary {
+    pub url: String,
+    pub method: String,
+    pub status_code: i32,
+}
+
+/// A log of the calls made to the third party, returned when `is_debug_mode` is set.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct DebugModeLog {
+    pub log_id: String,
+    pub dashboard_view: String,
+    pub log_summary: DebugModelLogSummary,
+}
+
+/// The response to a `POST` or `PATCH` of a common model.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WriteResponse<T> {
+    pub model: T,
+    #[serde(default)]
+    pub warnings: Vec<ValidationProblem>,
+    #[serde(default)]
+    pub errors: Vec<ValidationProblem>,
+    #[serde(default)]
+    pub logs: Option<Vec<DebugModeLog>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkedAccountStatus {
+    pub linked_account_status: String,
+    pub can_make_request: bool,
+}
+
+/// The fields a linked account's integration accepts for a write, from the `/meta` endpoints.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetaResponse {
+    pub request_schema: serde_json::Value,
+    #[serde(default)]
+    pub remote_field_classes: Option<serde_json::Value>,
+    #[serde(default)]
+    pub status: Option<LinkedAccountStatus>,
+    pub has_conditional_params: bool,
+    pub has_required_linked_account_params: bool,
+}
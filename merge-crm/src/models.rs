@@ -0,0 +1,14 @@
+pub mod accounts;
+pub mod association_types;
+pub mod associations;
+pub mod contacts;
+pub mod custom_object_classes;
+pub mod custom_objects;
+pub mod engagement_types;
+pub mod engagements;
+pub mod leads;
+pub mod notes;
+pub mod opportunities;
+pub mod stages;
+pub mod tasks;
+pub mod users;
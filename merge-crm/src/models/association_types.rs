@@ -0,0 +1,401 @@
+use crate::configuration::CRMConfig;
+use chrono::{DateTime, Utc};
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::write::WriteResponse;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AssociationTypeModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub source_object_class: Option<ObjectClass>,
+    #[builder(setter(into, strip_option), default)]
+    pub target_object_classes: Option<Vec<ObjectClass>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_key_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub display_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub cardinality: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_required: Option<bool>,
+}
+
+/// A custom object class by id, or a common model such as `Opportunity` by name.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct ObjectClass {
+    pub id: String,
+    pub origin_type: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/association-types", return_type=Paginated<AssociationTypeModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `AssociationTypeModel` that `expand` can return in full.
+    pub enum AssociationTypeExpand {
+        TargetObjectClasses => "target_object_classes",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AssociationTypeExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<AssociationTypeModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/association-types", return_type=AssociationTypeModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AssociationTypeExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+}
+
+/// A new association type, linking objects of `source_object_class` to objects of any of the
+/// `target_object_classes`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AssociationTypeRequest {
+    pub source_object_class: ObjectClass,
+    pub target_object_classes: Vec<ObjectClass>,
+    pub remote_key_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub cardinality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub is_required: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/association-types", return_type=WriteResponse<AssociationTypeModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    pub model: AssociationTypeRequest,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+pub type CreateRequestResponse = WriteResponse<AssociationTypeModel>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/association-types?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                      \"remote_id\": \"19202938\",
+                      \"created_at\": \"2021-12-06T10:11:26Z\",
+                      \"modified_at\": \"2021-12-06T10:11:26Z\",
+                      \"source_object_class\": {
+                        \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                        \"origin_type\": \"CUSTOM_OBJECT\"
+                      },
+                      \"target_object_classes\": [
+                        {
+                          \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                          \"origin_type\": \"CUSTOM_OBJECT\"
+                        }
+                      ],
+                      \"remote_key_name\": \"order_id\",
+                      \"display_name\": \"Order Id\",
+                      \"cardinality\": \"ONE_TO_MANY\",
+                      \"is_required\": true
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = AssociationTypeModelBuilder::default()
+            .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .source_object_class(
+                ObjectClassBuilder::default()
+                    .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+                    .origin_type("CUSTOM_OBJECT")
+                    .build()
+                    .unwrap(),
+            )
+            .target_object_classes(vec![ObjectClassBuilder::default()
+                .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+                .origin_type("CUSTOM_OBJECT")
+                .build()
+                .unwrap()])
+            .remote_key_name("order_id")
+            .display_name("Order Id")
+            .cardinality("ONE_TO_MANY")
+            .is_required(true)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/association-types/7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+              \"remote_id\": \"19202938\",
+              \"created_at\": \"2021-12-06T10:11:26Z\",
+              \"modified_at\": \"2021-12-06T10:11:26Z\",
+              \"source_object_class\": {
+                \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                \"origin_type\": \"CUSTOM_OBJECT\"
+              },
+              \"target_object_classes\": [
+                {
+                  \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                  \"origin_type\": \"CUSTOM_OBJECT\"
+                }
+              ],
+              \"remote_key_name\": \"order_id\",
+              \"display_name\": \"Order Id\",
+              \"cardinality\": \"ONE_TO_MANY\",
+              \"is_required\": true
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AssociationTypeModelBuilder::default()
+            .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .source_object_class(
+                ObjectClassBuilder::default()
+                    .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+                    .origin_type("CUSTOM_OBJECT")
+                    .build()
+                    .unwrap(),
+            )
+            .target_object_classes(vec![ObjectClassBuilder::default()
+                .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+                .origin_type("CUSTOM_OBJECT")
+                .build()
+                .unwrap()])
+            .remote_key_name("order_id")
+            .display_name("Order Id")
+            .cardinality("ONE_TO_MANY")
+            .is_required(true)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .params(params)
+            .id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .build()
+            .unwrap();
+
+        let response: AssociationTypeModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_an_association_type() {
+        let m = mock("POST", "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/association-types")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+    "model": {
+        "source_object_class": {
+            "id": "3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0",
+            "origin_type": "CUSTOM_OBJECT"
+        },
+        "target_object_classes": [
+            {
+                "id": "Opportunity",
+                "origin_type": "COMMON_MODEL"
+            }
+        ],
+        "remote_key_name": "order_opportunity",
+        "cardinality": "ONE_TO_MANY"
+    }
+})))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\",
+                    \"remote_key_name\": \"order_opportunity\",
+                    \"cardinality\": \"ONE_TO_MANY\"
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let association_type: AssociationTypeRequest = AssociationTypeRequestBuilder::default()
+            .source_object_class(
+                ObjectClassBuilder::default()
+                    .id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+                    .origin_type("CUSTOM_OBJECT")
+                    .build()
+                    .unwrap(),
+            )
+            .target_object_classes(vec![ObjectClassBuilder::default()
+                .id("Opportunity")
+                .origin_type("COMMON_MODEL")
+                .build()
+                .unwrap()])
+            .remote_key_name("order_opportunity")
+            .cardinality("ONE_TO_MANY")
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .model(association_type)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.model.remote_key_name,
+            Some("order_opportunity".to_string())
+        );
+        m.assert()
+    }
+}
@@ -0,0 +1,190 @@
+use crate::configuration::CRMConfig;
+use crate::models::association_types::AssociationTypeModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AssociationModel {
+    #[builder(setter(into, strip_option), default)]
+    pub created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub source_object: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub target_object: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub association_type: Option<Expandable<AssociationTypeModel>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/custom-objects/{object_id}/associations", return_type=Paginated<AssociationModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    pub object_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `AssociationModel` that `expand` can return in full.
+    pub enum AssociationExpand {
+        AssociationType => "association_type",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub association_type_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AssociationExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+}
+
+pub type GetRequestResponse = Paginated<AssociationModel>;
+
+/// Links the custom object `source_object_id` to `target_object_id` through an existing
+/// association type.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{source_class_id}/custom-objects/{source_object_id}/associations/{target_class_id}/{target_object_id}/{association_type_id}", return_type=AssociationModel, method="PUT")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub source_class_id: String,
+    pub source_object_id: String,
+    pub target_class_id: String,
+    pub target_object_id: String,
+    pub association_type_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/custom-objects/5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6/associations?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"created_at\": \"2021-12-06T10:11:26Z\",
+                      \"modified_at\": \"2021-12-06T10:11:26Z\",
+                      \"source_object\": \"5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6\",
+                      \"target_object\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                      \"association_type\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\"
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = AssociationModelBuilder::default()
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .source_object("5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6")
+            .target_object("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .association_type("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .object_id("5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_an_association() {
+        let m = mock("PUT", "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/custom-objects/5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6/associations/Opportunity/0958cbc6-6040-430a-848e-aafacbadf4ae/7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"source_object\": \"5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6\",
+                  \"target_object\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                  \"association_type\": \"7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1\"
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .source_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .source_object_id("5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6")
+            .target_class_id("Opportunity")
+            .target_object_id("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .association_type_id("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.target_object,
+            Some("0958cbc6-6040-430a-848e-aafacbadf4ae".to_string())
+        );
+        assert_eq!(
+            response.association_type.as_ref().and_then(Expandable::id),
+            Some("7e9a3ed4-33ec-4a7a-9e5f-ecb2e36bf3a1")
+        );
+        m.assert()
+    }
+}
@@ -0,0 +1,315 @@
+use crate::configuration::CRMConfig;
+use chrono::{DateTime, Utc};
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CustomObjectClassModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub labels: Option<CustomObjectClassLabels>,
+    #[builder(setter(into, strip_option), default)]
+    pub fields: Option<Vec<CustomObjectClassField>>,
+    #[builder(setter(into, strip_option), default)]
+    pub association_types: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CustomObjectClassLabels {
+    #[builder(setter(into, strip_option), default)]
+    pub singular: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub plural: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CustomObjectClassField {
+    #[builder(setter(into, strip_option), default)]
+    pub remote_key_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub display_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_required: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub field_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub field_format: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub field_choices: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes", return_type=Paginated<CustomObjectClassModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `CustomObjectClassModel` that `expand` can return in full.
+    pub enum CustomObjectClassExpand {
+        Fields => "fields",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CustomObjectClassExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<CustomObjectClassModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes", return_type=CustomObjectClassModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<CustomObjectClassExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/custom-object-classes?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"created_at\": \"2021-12-06T10:11:26Z\",
+                      \"modified_at\": \"2021-12-06T10:11:26Z\",
+                      \"name\": \"Order\",
+                      \"description\": \"Orders placed through the storefront\",
+                      \"labels\": {
+                        \"singular\": \"Order\",
+                        \"plural\": \"Orders\"
+                      },
+                      \"fields\": [
+                        {
+                          \"remote_key_name\": \"order_id\",
+                          \"display_name\": \"Order Id\",
+                          \"description\": \"The order number\",
+                          \"is_required\": true,
+                          \"field_type\": \"string\",
+                          \"field_format\": \"string\",
+                          \"field_choices\": []
+                        }
+                      ],
+                      \"association_types\": []
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CustomObjectClassModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .name("Order")
+            .description("Orders placed through the storefront")
+            .labels(
+                CustomObjectClassLabelsBuilder::default()
+                    .singular("Order")
+                    .plural("Orders")
+                    .build()
+                    .unwrap(),
+            )
+            .fields(vec![CustomObjectClassFieldBuilder::default()
+                .remote_key_name("order_id")
+                .display_name("Order Id")
+                .description("The order number")
+                .is_required(true)
+                .field_type("string")
+                .field_format("string")
+                .field_choices(vec![])
+                .build()
+                .unwrap()])
+            .association_types(vec![])
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/custom-object-classes/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"created_at\": \"2021-12-06T10:11:26Z\",
+              \"modified_at\": \"2021-12-06T10:11:26Z\",
+              \"name\": \"Order\",
+              \"description\": \"Orders placed through the storefront\",
+              \"labels\": {
+                \"singular\": \"Order\",
+                \"plural\": \"Orders\"
+              },
+              \"fields\": [
+                {
+                  \"remote_key_name\": \"order_id\",
+                  \"display_name\": \"Order Id\",
+                  \"description\": \"The order number\",
+                  \"is_required\": true,
+                  \"field_type\": \"string\",
+                  \"field_format\": \"string\",
+                  \"field_choices\": []
+                }
+              ],
+              \"association_types\": []
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CustomObjectClassModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .name("Order")
+            .description("Orders placed through the storefront")
+            .labels(
+                CustomObjectClassLabelsBuilder::default()
+                    .singular("Order")
+                    .plural("Orders")
+                    .build()
+                    .unwrap(),
+            )
+            .fields(vec![CustomObjectClassFieldBuilder::default()
+                .remote_key_name("order_id")
+                .display_name("Order Id")
+                .description("The order number")
+                .is_required(true)
+                .field_type("string")
+                .field_format("string")
+                .field_choices(vec![])
+                .build()
+                .unwrap()])
+            .association_types(vec![])
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: CustomObjectClassModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,324 @@
+use crate::configuration::CRMConfig;
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteField;
+use merge_config::write::WriteResponse;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CustomObjectModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub object_class: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub fields: Option<serde_json::Value>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<Vec<RemoteField>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/custom-objects", return_type=Paginated<CustomObjectModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<CustomObjectModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/custom-objects", return_type=CustomObjectModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+}
+
+/// The values of a new custom object, keyed by the `remote_key_name` of the fields of its class.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct CustomObjectRequest {
+    pub fields: serde_json::Value,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="custom-object-classes/{custom_object_class_id}/custom-objects", return_type=WriteResponse<CustomObjectModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub custom_object_class_id: String,
+    pub model: CustomObjectRequest,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+pub type CreateRequestResponse = WriteResponse<CustomObjectModel>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteFieldBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/custom-objects?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"created_at\": \"2021-12-06T10:11:26Z\",
+                      \"modified_at\": \"2021-12-06T10:11:26Z\",
+                      \"object_class\": \"15260a3d-297c-4e8b-96e1-987758d12b42\",
+                      \"fields\": {
+                        \"order_id\": \"ORD-1234\",
+                        \"total\": 125
+                      },
+                      \"remote_fields\": [
+                        {
+                          \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                          \"value\": \"ORD-1234\"
+                        }
+                      ]
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = CustomObjectModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .object_class("15260a3d-297c-4e8b-96e1-987758d12b42")
+            .fields(serde_json::json!({"order_id": "ORD-1234", "total": 125}))
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/custom-objects/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"created_at\": \"2021-12-06T10:11:26Z\",
+              \"modified_at\": \"2021-12-06T10:11:26Z\",
+              \"object_class\": \"15260a3d-297c-4e8b-96e1-987758d12b42\",
+              \"fields\": {
+                \"order_id\": \"ORD-1234\",
+                \"total\": 125
+              },
+              \"remote_fields\": [
+                {
+                  \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                  \"value\": \"ORD-1234\"
+                }
+              ]
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = CustomObjectModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .created_at("2021-12-06T10:11:26Z")
+            .modified_at("2021-12-06T10:11:26Z")
+            .object_class("15260a3d-297c-4e8b-96e1-987758d12b42")
+            .fields(serde_json::json!({"order_id": "ORD-1234", "total": 125}))
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: CustomObjectModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_custom_object() {
+        let m = mock(
+            "POST",
+            "/api/crm/v1/custom-object-classes/3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0/custom-objects",
+        )
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": {
+                "fields": {
+                    "order_id": "ORD-1234",
+                    "total": 125
+                }
+            }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"model\": {
+                    \"id\": \"5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6\",
+                    \"object_class\": \"3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0\",
+                    \"fields\": {
+                      \"order_id\": \"ORD-1234\",
+                      \"total\": 125
+                    }
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let custom_object: CustomObjectRequest = CustomObjectRequestBuilder::default()
+            .fields(serde_json::json!({"order_id": "ORD-1234", "total": 125}))
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .custom_object_class_id("3d3f0ed2-54ea-4bd4-9d5c-3a63b9c8e2e0")
+            .model(custom_object)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "5bb7f26a-b4e4-4f8e-8f1f-2e3ad8a4fbd6");
+        assert_eq!(response.model.fields.unwrap()["total"], 125);
+        m.assert()
+    }
+}
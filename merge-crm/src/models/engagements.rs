@@ -0,0 +1,323 @@
+use crate::configuration::CRMConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::contacts::ContactModel;
+use crate::models::engagement_types::EngagementTypeModel;
+use crate::models::users::UserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::{RemoteData, RemoteField};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EngagementModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub owner: Option<Expandable<UserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub content: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub subject: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub direction: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub engagement_type: Option<Expandable<EngagementTypeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_time: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_time: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub contacts: Option<Vec<Expandable<ContactModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<Vec<RemoteField>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="engagements", return_type=Paginated<EngagementModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `EngagementModel` that `expand` can return in full.
+    pub enum EngagementExpand {
+        Owner => "owner",
+        Account => "account",
+        Contacts => "contacts",
+        EngagementType => "engagement_type",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EngagementExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_before: Option<DateTime<Utc>>,
+}
+
+pub type GetRequestResponse = Paginated<EngagementModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="engagements", return_type=EngagementModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EngagementExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::{RemoteDataBuilder, RemoteFieldBuilder};
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/crm/v1/engagements?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"owner\": \"72122ce9-6bfe-466e-9396-d2e25225d70a\",
+                      \"content\": \"Meeting notes\",
+                      \"subject\": \"Call with Gil\",
+                      \"direction\": \"OUTBOUND\",
+                      \"engagement_type\": \"12941aa1-9c4d-4a5d-9c2c-dc5703ac1b7b\",
+                      \"start_time\": \"2021-12-06T10:11:26Z\",
+                      \"end_time\": \"2021-12-06T10:11:26Z\",
+                      \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                      \"contacts\": [
+                        \"a7e13c4e-0d36-4c42-a2e1-ba0a4c1d3d35\"
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/engagements\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_fields\": [
+                        {
+                          \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                          \"value\": \"ORD-1234\"
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = EngagementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .owner("72122ce9-6bfe-466e-9396-d2e25225d70a")
+            .content("Meeting notes")
+            .subject("Call with Gil")
+            .direction("OUTBOUND")
+            .engagement_type("12941aa1-9c4d-4a5d-9c2c-dc5703ac1b7b")
+            .start_time("2021-12-06T10:11:26Z")
+            .end_time("2021-12-06T10:11:26Z")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contacts(vec![Expandable::from(
+                "a7e13c4e-0d36-4c42-a2e1-ba0a4c1d3d35",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/engagements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/engagements/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"owner\": \"72122ce9-6bfe-466e-9396-d2e25225d70a\",
+              \"content\": \"Meeting notes\",
+              \"subject\": \"Call with Gil\",
+              \"direction\": \"OUTBOUND\",
+              \"engagement_type\": \"12941aa1-9c4d-4a5d-9c2c-dc5703ac1b7b\",
+              \"start_time\": \"2021-12-06T10:11:26Z\",
+              \"end_time\": \"2021-12-06T10:11:26Z\",
+              \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+              \"contacts\": [
+                \"a7e13c4e-0d36-4c42-a2e1-ba0a4c1d3d35\"
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/engagements\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_fields\": [
+                {
+                  \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                  \"value\": \"ORD-1234\"
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = EngagementModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .owner("72122ce9-6bfe-466e-9396-d2e25225d70a")
+            .content("Meeting notes")
+            .subject("Call with Gil")
+            .direction("OUTBOUND")
+            .engagement_type("12941aa1-9c4d-4a5d-9c2c-dc5703ac1b7b")
+            .start_time("2021-12-06T10:11:26Z")
+            .end_time("2021-12-06T10:11:26Z")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contacts(vec![Expandable::from(
+                "a7e13c4e-0d36-4c42-a2e1-ba0a4c1d3d35",
+            )])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/engagements")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: EngagementModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
@@ -0,0 +1,456 @@
+use crate::configuration::CRMConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::contacts::ContactModel;
+use crate::models::users::UserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::{RemoteData, RemoteField};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct LeadModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub owner: Option<Expandable<UserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub lead_source: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub addresses: Option<Vec<Address>>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_addresses: Option<Vec<EmailAddress>>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_numbers: Option<Vec<PhoneNumber>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub converted_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub converted_contact: Option<Expandable<ContactModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub converted_account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<Vec<RemoteField>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Address {
+    #[builder(setter(into, strip_option), default)]
+    pub street_1: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub street_2: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub city: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub state: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub postal_code: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub country: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub address_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EmailAddress {
+    #[builder(setter(into, strip_option), default)]
+    pub email_address: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_address_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct PhoneNumber {
+    #[builder(setter(into, strip_option), default)]
+    pub phone_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_number_type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="leads", return_type=Paginated<LeadModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `LeadModel` that `expand` can return in full.
+    pub enum LeadExpand {
+        Owner => "owner",
+        ConvertedContact => "converted_contact",
+        ConvertedAccount => "converted_account",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub converted_account_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub converted_contact_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub email_addresses: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<LeadExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub owner_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_numbers: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<LeadModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="crm", model="leads", return_type=LeadModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<CRMConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<LeadExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_fields: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::{RemoteDataBuilder, RemoteFieldBuilder};
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/crm/v1/leads?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"owner\": \"72122ce9-6bfe-466e-9396-d2e25225d70a\",
+                      \"lead_source\": \"API Blogger\",
+                      \"title\": \"Co-Founder\",
+                      \"company\": \"Merge API\",
+                      \"first_name\": \"Gil\",
+                      \"last_name\": \"Feig\",
+                      \"addresses\": [
+                        {
+                          \"street_1\": \"50 Bowling Green Dr\",
+                          \"street_2\": \"Golden Gate Park\",
+                          \"city\": \"San Francisco\",
+                          \"state\": \"California\",
+                          \"postal_code\": \"94122\",
+                          \"country\": \"US\",
+                          \"address_type\": \"Shipping\"
+                        }
+                      ],
+                      \"email_addresses\": [
+                        {
+                          \"email_address\": \"hello@merge.dev\",
+                          \"email_address_type\": \"Work\"
+                        }
+                      ],
+                      \"phone_numbers\": [
+                        {
+                          \"phone_number\": \"+3198675309\",
+                          \"phone_number_type\": \"Mobile\"
+                        }
+                      ],
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"converted_at\": \"2021-12-06T10:11:26Z\",
+                      \"converted_contact\": \"3124ac05-f933-4bf4-92ed-fc4882795e12\",
+                      \"converted_account\": \"3245cfe0-26a9-48f1-996b-98a08620c4ad\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/leads\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_fields\": [
+                        {
+                          \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                          \"value\": \"ORD-1234\"
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = LeadModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .owner("72122ce9-6bfe-466e-9396-d2e25225d70a")
+            .lead_source("API Blogger")
+            .title("Co-Founder")
+            .company("Merge API")
+            .first_name("Gil")
+            .last_name("Feig")
+            .addresses(vec![AddressBuilder::default()
+                .street_1("50 Bowling Green Dr")
+                .street_2("Golden Gate Park")
+                .city("San Francisco")
+                .state("California")
+                .postal_code("94122")
+                .country("US")
+                .address_type("Shipping")
+                .build()
+                .unwrap()])
+            .email_addresses(vec![EmailAddressBuilder::default()
+                .email_address("hello@merge.dev")
+                .email_address_type("Work")
+                .build()
+                .unwrap()])
+            .phone_numbers(vec![PhoneNumberBuilder::default()
+                .phone_number("+3198675309")
+                .phone_number_type("Mobile")
+                .build()
+                .unwrap()])
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .converted_at("2021-12-06T10:11:26Z")
+            .converted_contact("3124ac05-f933-4bf4-92ed-fc4882795e12")
+            .converted_account("3245cfe0-26a9-48f1-996b-98a08620c4ad")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/leads")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/crm/v1/leads/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"owner\": \"72122ce9-6bfe-466e-9396-d2e25225d70a\",
+              \"lead_source\": \"API Blogger\",
+              \"title\": \"Co-Founder\",
+              \"company\": \"Merge API\",
+              \"first_name\": \"Gil\",
+              \"last_name\": \"Feig\",
+              \"addresses\": [
+                {
+                  \"street_1\": \"50 Bowling Green Dr\",
+                  \"street_2\": \"Golden Gate Park\",
+                  \"city\": \"San Francisco\",
+                  \"state\": \"California\",
+                  \"postal_code\": \"94122\",
+                  \"country\": \"US\",
+                  \"address_type\": \"Shipping\"
+                }
+              ],
+              \"email_addresses\": [
+                {
+                  \"email_address\": \"hello@merge.dev\",
+                  \"email_address_type\": \"Work\"
+                }
+              ],
+              \"phone_numbers\": [
+                {
+                  \"phone_number\": \"+3198675309\",
+                  \"phone_number_type\": \"Mobile\"
+                }
+              ],
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"converted_at\": \"2021-12-06T10:11:26Z\",
+              \"converted_contact\": \"3124ac05-f933-4bf4-92ed-fc4882795e12\",
+              \"converted_account\": \"3245cfe0-26a9-48f1-996b-98a08620c4ad\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/leads\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_fields\": [
+                {
+                  \"remote_field_class\": \"0c16c3c7-b211-4a51-9fc6-609bcbdf875a\",
+                  \"value\": \"ORD-1234\"
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = LeadModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .owner("72122ce9-6bfe-466e-9396-d2e25225d70a")
+            .lead_source("API Blogger")
+            .title("Co-Founder")
+            .company("Merge API")
+            .first_name("Gil")
+            .last_name("Feig")
+            .addresses(vec![AddressBuilder::default()
+                .street_1("50 Bowling Green Dr")
+                .street_2("Golden Gate Park")
+                .city("San Francisco")
+                .state("California")
+                .postal_code("94122")
+                .country("US")
+                .address_type("Shipping")
+                .build()
+                .unwrap()])
+            .email_addresses(vec![EmailAddressBuilder::default()
+                .email_address("hello@merge.dev")
+                .email_address_type("Work")
+                .build()
+                .unwrap()])
+            .phone_numbers(vec![PhoneNumberBuilder::default()
+                .phone_number("+3198675309")
+                .phone_number_type("Mobile")
+                .build()
+                .unwrap()])
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .converted_at("2021-12-06T10:11:26Z")
+            .converted_contact("3124ac05-f933-4bf4-92ed-fc4882795e12")
+            .converted_account("3245cfe0-26a9-48f1-996b-98a08620c4ad")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/leads")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_fields(vec![RemoteFieldBuilder::default()
+                .remote_field_class("0c16c3c7-b211-4a51-9fc6-609bcbdf875a")
+                .value(serde_json::json!("ORD-1234"))
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = CRMConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: LeadModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
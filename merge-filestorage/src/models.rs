@@ -0,0 +1,5 @@
+pub mod drives;
+pub mod files;
+pub mod folders;
+pub mod groups;
+pub mod users;
@@ -0,0 +1,734 @@
+use crate::configuration::FileStorageConfig;
+use crate::models::drives::DriveModel;
+use crate::models::folders::FolderModel;
+use chrono::{DateTime, Utc};
+use merge_config::download::{ByteRange, Download};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::upload::FileUpload;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct FileModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_thumbnail_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub size: Option<i64>,
+    #[builder(setter(into, strip_option), default)]
+    pub mime_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub folder: Option<Expandable<FolderModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<serde_json::Value>,
+    #[builder(setter(into, strip_option), default)]
+    pub drive: Option<Expandable<DriveModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="filestorage", model="files", return_type=Paginated<FileModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<FileStorageConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `FileModel` that `expand` can return in full.
+    pub enum FileExpand {
+        Folder => "folder",
+        Drive => "drive",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub drive_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<FileExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub folder_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub mime_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<FileModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="filestorage", model="files", return_type=FileModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<FileStorageConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<FileExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+/// The fields accepted when uploading a file. Which of them a linked account's integration
+/// actually needs can be discovered with `MetaPostRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct FileRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub drive: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub permissions: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+/// Uploads a file. The model is sent as the `model` part of a multipart form and the content as
+/// its `file` part.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="filestorage", model="files", return_type=WriteResponse<FileModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<FileStorageConfig>,
+    pub model: FileRequest,
+    pub file: FileUpload,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<FileModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="filestorage", model="files/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<FileStorageConfig>,
+}
+
+/// Streams the content of a file, see `Download`. Set `range` to resume an interrupted download
+/// from the bytes already written.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="filestorage", model="files/{id}/download", return_type=Download)]
+pub struct DownloadRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<FileStorageConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub range: Option<ByteRange>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<DownloadRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct DownloadRequestParams {
+    /// Exports files from Google Drive in this format, e.g. `application/pdf`.
+    #[builder(setter(into, strip_option), default)]
+    pub mime_type: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::download::ContentRange;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/filestorage/v1/files?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Q1 Roadmap.pdf\",
+                      \"file_url\": \"https://drive.com/1234\",
+                      \"file_thumbnail_url\": \"https://drive.com/1234/thumbnail.png\",
+                      \"size\": 1254,
+                      \"mime_type\": \"application/pdf\",
+                      \"description\": \"All of the company's plans for Q1\",
+                      \"folder\": \"851148b4-fd8f-47ae-94bd-9100c5c0c898\",
+                      \"permissions\": [
+                        {
+                          \"type\": \"ANYONE\",
+                          \"roles\": [
+                            \"READ\"
+                          ]
+                        }
+                      ],
+                      \"drive\": \"4aa1e741-4849-477e-9d4c-076c08782d3a\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/files\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = FileModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Q1 Roadmap.pdf")
+            .file_url("https://drive.com/1234")
+            .file_thumbnail_url("https://drive.com/1234/thumbnail.png")
+            .size(1254)
+            .mime_type("application/pdf")
+            .description("All of the company's plans for Q1")
+            .folder("851148b4-fd8f-47ae-94bd-9100c5c0c898")
+            .permissions(serde_json::json!([{"type": "ANYONE", "roles": ["READ"]}]))
+            .drive("4aa1e741-4849-477e-9d4c-076c08782d3a")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/files")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/filestorage/v1/files/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Q1 Roadmap.pdf\",
+              \"file_url\": \"https://drive.com/1234\",
+              \"file_thumbnail_url\": \"https://drive.com/1234/thumbnail.png\",
+              \"size\": 1254,
+              \"mime_type\": \"application/pdf\",
+              \"description\": \"All of the company's plans for Q1\",
+              \"folder\": \"851148b4-fd8f-47ae-94bd-9100c5c0c898\",
+              \"permissions\": [
+                {
+                  \"type\": \"ANYONE\",
+                  \"roles\": [
+                    \"READ\"
+                  ]
+                }
+              ],
+              \"drive\": \"4aa1e741-4849-477e-9d4c-076c08782d3a\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/files\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = FileModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Q1 Roadmap.pdf")
+            .file_url("https://drive.com/1234")
+            .file_thumbnail_url("https://drive.com/1234/thumbnail.png")
+            .size(1254)
+            .mime_type("application/pdf")
+            .description("All of the company's plans for Q1")
+            .folder("851148b4-fd8f-47ae-94bd-9100c5c0c898")
+            .permissions(serde_json::json!([{"type": "ANYONE", "roles": ["READ"]}]))
+            .drive("4aa1e741-4849-477e-9d4c-076c08782d3a")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/files")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: FileModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_files_by_folder() {
+        let m = mock(
+            "GET",
+            "/api/filestorage/v1/files?drive_id=31a4a1f4-1a2d-4a6e-9e6f-2a3e7ea9d6b4&folder_id=a5b3c7e4-87d2-4b0a-b1a3-66e0f1fa23c8",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .drive_id("31a4a1f4-1a2d-4a6e-9e6f-2a3e7ea9d6b4")
+            .folder_id("a5b3c7e4-87d2-4b0a-b1a3-66e0f1fa23c8")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_upload_a_file() {
+        let m = mock("POST", "/api/filestorage/v1/files?run_async=true")
+            .match_header(
+                "content-type",
+                Matcher::Regex("^multipart/form-data; boundary=".to_string()),
+            )
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(
+                    r#"name="model"\r\n\r\n\{"name":"Q1 Roadmap.pdf","folder":"a5b3c7e4-87d2-4b0a-b1a3-66e0f1fa23c8"\}"#
+                        .to_string(),
+                ),
+                Matcher::Regex(
+                    r#"name="file"; filename="Q1 Roadmap.pdf"\r\nContent-Type: application/pdf\r\n\r\n%PDF-1.7"#
+                        .to_string(),
+                ),
+            ]))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                    \"name\": \"Q1 Roadmap.pdf\",
+                    \"folder\": \"a5b3c7e4-87d2-4b0a-b1a3-66e0f1fa23c8\",
+                    \"size\": 8,
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let file: FileRequest = FileRequestBuilder::default()
+            .name("Q1 Roadmap.pdf")
+            .folder("a5b3c7e4-87d2-4b0a-b1a3-66e0f1fa23c8")
+            .build()
+            .unwrap();
+
+        let params: CreateRequestParams = CreateRequestParamsBuilder::default()
+            .run_async(true)
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(file)
+            .file(FileUpload::new("Q1 Roadmap.pdf", "%PDF-1.7").with_mime_type("application/pdf"))
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "0958cbc6-6040-430a-848e-aafacbadf4ae");
+        assert_eq!(response.model.size, Some(8));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_upload_a_file_from_disk() {
+        let m = mock("POST", "/api/filestorage/v1/files")
+            .match_header("content-length", Matcher::Any)
+            .match_body(Matcher::Regex(
+                r#"name="file"; filename="merge-upload-notes.txt"\r\n\r\nMeeting notes"#
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                    \"name\": \"merge-upload-notes.txt\",
+                    \"size\": 13,
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let path = std::env::temp_dir()
+            .join(format!("merge-upload-{}", std::process::id()))
+            .join("merge-upload-notes.txt");
+        tokio::fs::create_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        tokio::fs::write(&path, "Meeting notes").await.unwrap();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let file = FileUpload::from_path(&path).await.unwrap();
+        assert_eq!(file.file_name, "merge-upload-notes.txt");
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(
+                FileRequestBuilder::default()
+                    .name("merge-upload-notes.txt")
+                    .build()
+                    .unwrap(),
+            )
+            .file(file)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await;
+        tokio::fs::remove_dir_all(path.parent().unwrap())
+            .await
+            .unwrap();
+        let response = response.unwrap();
+
+        assert_eq!(response.model.size, Some(13));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_upload_a_file_from_a_reader() {
+        let m = mock("POST", "/api/filestorage/v1/files")
+            .match_body(Matcher::Regex(
+                r#"name="file"; filename="notes.txt"\r\nContent-Type: text/plain\r\n\r\nMeeting notes"#
+                    .to_string(),
+            ))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                    \"name\": \"notes.txt\",
+                    \"size\": 13,
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let reader = std::io::Cursor::new(b"Meeting notes".to_vec());
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(
+                FileRequestBuilder::default()
+                    .name("notes.txt")
+                    .build()
+                    .unwrap(),
+            )
+            .file(FileUpload::from_reader("notes.txt", 13, reader).with_mime_type("text/plain"))
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.name.as_deref(), Some("notes.txt"));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/filestorage/v1/files/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"has_conditional_params\": false,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "name"
+        );
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_read_a_download() {
+        let content = "a".repeat(64 * 1024);
+        let m = mock(
+            "GET",
+            "/api/filestorage/v1/files/0958cbc6-6040-430a-848e-aafacbadf4ae/download?mime_type=application%2Fpdf",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/pdf")
+        .with_header("content-disposition", "attachment; filename=\"Q1 Roadmap.pdf\"")
+        .with_body(&content)
+        .expect(1)
+        .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: DownloadRequestParams = DownloadRequestParamsBuilder::default()
+            .mime_type("application/pdf")
+            .build()
+            .unwrap();
+
+        let request = DownloadRequestBuilder::default()
+            .config(config)
+            .id("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let download = request.send_request().await.unwrap();
+        assert_eq!(download.file_name(), Some("Q1 Roadmap.pdf".to_string()));
+        assert!(!download.is_partial());
+
+        let mut file = vec![];
+        download
+            .into_async_read()
+            .read_to_end(&mut file)
+            .await
+            .unwrap();
+        assert_eq!(file, content.as_bytes());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_resume_a_download() {
+        let m = mock(
+            "GET",
+            "/api/filestorage/v1/files/0958cbc6-6040-430a-848e-aafacbadf4ae/download",
+        )
+        .match_header("range", "bytes=4-")
+        .with_status(206)
+        .with_header("content-range", "bytes 4-7/8")
+        .with_body("-1.7")
+        .expect(1)
+        .create();
+
+        let config = FileStorageConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let mut file = b"%PDF".to_vec();
+
+        let request = DownloadRequestBuilder::default()
+            .config(config)
+            .id("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .range(file.len() as u64..)
+            .build()
+            .unwrap();
+
+        let mut download = request.send_request().await.unwrap();
+        assert!(download.is_partial());
+        assert_eq!(
+            download.content_range(),
+            Some(ContentRange {
+                start: 4,
+                end: 7,
+                total: Some(8)
+            })
+        );
+
+        while let Some(chunk) = download.chunk().await.unwrap() {
+            file.extend_from_slice(&chunk);
+        }
+        assert_eq!(file, b"%PDF-1.7");
+        m.assert()
+    }
+}
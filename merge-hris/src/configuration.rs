@@ -1,3 +1,4 @@
+pub use merge_config::configuration::Region;
 use merge_config::configuration::Configuration;
 
 pub type HRISConfig = Configuration;
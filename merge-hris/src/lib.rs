@@ -4,3 +4,15 @@ extern crate derive_builder;
 pub mod models;
 
 pub mod configuration;
+
+pub use merge_config::account;
+pub use merge_config::client;
+pub use merge_config::error;
+pub use merge_config::expand;
+pub use merge_config::link;
+pub use merge_config::pagination;
+pub use merge_config::passthrough;
+pub use merge_config::query;
+pub use merge_config::remote;
+pub use merge_config::retry;
+pub use merge_config::write;
@@ -1,2 +1,17 @@
 pub mod bank_info;
 pub mod benefits;
+pub mod companies;
+pub mod deductions;
+pub mod dependents;
+pub mod employee_payroll_runs;
+pub mod employees;
+pub mod employer_benefits;
+pub mod employments;
+pub mod groups;
+pub mod locations;
+pub mod pay_groups;
+pub mod payroll_runs;
+pub mod teams;
+pub mod time_off;
+pub mod time_off_balances;
+pub mod timesheet_entries;
@@ -1,49 +1,54 @@
 use crate::configuration::HRISConfig;
+use crate::models::employees::EmployeeModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
 use serde::{Deserialize, Serialize};
 
 use merge_proc_macros::{generate_url_params, send_request};
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[builder(setter(into))]
 pub struct BankInfoModel {
-    id: String,
-    #[builder(setter(into, strip_option), default)]
-    remote_id: Option<String>,
-    #[builder(setter(into, strip_option), default)]
-    employee: Option<String>,
+    pub id: String,
     #[builder(setter(into, strip_option), default)]
-    account_number: Option<String>,
+    pub remote_id: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    routing_number: Option<String>,
+    pub employee: Option<Expandable<EmployeeModel>>,
     #[builder(setter(into, strip_option), default)]
-    bank_name: Option<String>,
+    pub account_number: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    account_type: Option<String>,
+    pub routing_number: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    remote_created_at: Option<String>,
+    pub bank_name: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    remote_data: Option<Vec<RemoteData>>,
-    remote_was_deleted: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
-#[builder(setter(into))]
-pub struct RemoteData {
+    pub account_type: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub path: Option<String>,
+    pub remote_created_at: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub data: Option<Vec<String>>,
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(into))]
-#[send_request(service="hris", model="bank-info", return_type=GetRequestResponse)]
+#[send_request(service="hris", model="bank-info", return_type=Paginated<BankInfoModel>)]
 pub struct GetRequest {
-    pub config: HRISConfig,
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
     #[builder(setter(into, strip_option), default)]
     pub params: Option<GetRequestParams>,
 }
 
+expand_enum! {
+    /// The relations of `BankInfoModel` that `expand` can return in full.
+    pub enum BankInfoExpand {
+        Employee => "employee",
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(into))]
 #[generate_url_params]
@@ -53,23 +58,23 @@ pub struct GetRequestParams {
     #[builder(setter(into, strip_option), default)]
     pub bank_name: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub created_after: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
-    pub created_before: Option<String>,
+    pub created_before: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
     pub cursor: Option<String>,
     #[builder(setter(into, strip_option), default)]
     pub employee_id: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub expand: Option<String>,
+    pub expand: Option<Vec<BankInfoExpand>>,
     #[builder(setter(into, strip_option), default)]
     pub include_deleted_data: Option<bool>,
     #[builder(setter(into, strip_option), default)]
     pub include_remote_data: Option<bool>,
     #[builder(setter(into, strip_option), default)]
-    pub modified_after: Option<String>,
+    pub modified_after: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
-    pub modified_before: Option<String>,
+    pub modified_before: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
     pub order_by: Option<String>,
     #[builder(setter(into, strip_option), default)]
@@ -80,18 +85,14 @@ pub struct GetRequestParams {
     pub remote_id: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct GetRequestResponse {
-    pub next: Option<String>,
-    pub previous: Option<String>,
-    pub results: Vec<BankInfoModel>,
-}
+pub type GetRequestResponse = Paginated<BankInfoModel>;
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(into))]
 #[send_request(service="hris", model="bank-info", return_type=BankInfoModel)]
 pub struct GetRequestById {
-    pub config: HRISConfig,
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
     pub id: String,
     #[builder(setter(into, strip_option), default)]
     pub params: Option<GetRequestByIdParams>,
@@ -102,7 +103,7 @@ pub struct GetRequestById {
 #[generate_url_params]
 pub struct GetRequestByIdParams {
     #[builder(setter(into, strip_option), default)]
-    pub expand: Option<String>,
+    pub expand: Option<Vec<BankInfoExpand>>,
     #[builder(setter(into, strip_option), default)]
     pub include_remote_data: Option<bool>,
     #[builder(setter(into, strip_option), default)]
@@ -110,9 +111,17 @@ pub struct GetRequestByIdParams {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::get_first)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
+    use futures::TryStreamExt;
+    use merge_config::client::MergeClient;
+    use merge_config::error::MergeError;
+    use merge_config::remote::RemoteDataBuilder;
+    use merge_config::retry::{RetryPolicy, RetryPolicyBuilder};
     use mockito::mock;
+    use std::time::Duration;
 
     #[test]
     fn it_builds_the_model() {
@@ -138,6 +147,32 @@ mod tests {
         assert_eq!(model.remote_was_deleted, false);
     }
 
+    #[test]
+    fn it_encodes_the_query_string() {
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .bank_name("Wells Fargo & Co")
+            .created_after(Utc.with_ymd_and_hms(2021, 12, 6, 10, 11, 26).unwrap())
+            .cursor("cD0yMDIx+LTAx==")
+            .page_size(50)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params.generate_url_params(),
+            "?bank_name=Wells%20Fargo%20%26%20Co\
+             &created_after=2021-12-06T10%3A11%3A26Z\
+             &cursor=cD0yMDIx%2BLTAx%3D%3D\
+             &page_size=50"
+        );
+        assert_eq!(
+            GetRequestParamsBuilder::default()
+                .build()
+                .unwrap()
+                .generate_url_params(),
+            ""
+        );
+    }
+
     #[tokio::test]
     async fn it_successfully_sends_request() {
         let m = mock("GET", "/api/hris/v1/bank-info?include_remote_data=true")
@@ -172,7 +207,7 @@ mod tests {
             )
             .expect(1)
             .create();
-        let config = HRISConfig::new("test", "test");
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
 
         let expected_remote_data: RemoteData = RemoteDataBuilder::default()
             .path("/bank-info")
@@ -253,7 +288,7 @@ mod tests {
         .expect(1)
         .create();
 
-        let config = HRISConfig::new("test", "test");
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
 
         let request_params = GetRequestByIdParamsBuilder::default()
             .include_remote_data(true)
@@ -293,9 +328,108 @@ mod tests {
         m.assert()
     }
 
+    #[tokio::test]
+    async fn it_decodes_arbitrary_remote_data() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/bank-info/a1b2c3d4?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"id\": \"a1b2c3d4\",
+                  \"remote_data\": [
+                    {
+                      \"path\": \"/employees/123234/bank-accounts\",
+                      \"data\": {
+                        \"accountNumber\": \"439291590\",
+                        \"owner\": {\"id\": 123234, \"primary\": true}
+                      }
+                    }
+                  ],
+                  \"remote_was_deleted\": false
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request_params = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(request_params)
+            .id("a1b2c3d4")
+            .build()
+            .unwrap();
+
+        let result: BankInfoModel = request.send_request().await.unwrap();
+        let remote_data = result.remote_data.unwrap();
+        let remote_data =
+            RemoteData::find(&remote_data, "/employees/123234/bank-accounts").unwrap();
+
+        assert_eq!(remote_data.get::<i64>("owner.id"), Some(123234));
+        assert_eq!(remote_data.get::<bool>("owner.primary"), Some(true));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_expands_the_employee() {
+        let m = mock("GET", "/api/hris/v1/bank-info/a1b2c3d4?expand=employee")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"id\": \"a1b2c3d4\",
+                  \"employee\": {
+                    \"id\": \"a3617eb4-dfe3-426f-921e-a65fc1661e10\",
+                    \"first_name\": \"Greg\",
+                    \"manager\": \"0048ea5b-911e-4dff-9364-92070dea62ff\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"remote_was_deleted\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request_params = GetRequestByIdParamsBuilder::default()
+            .expand(vec![BankInfoExpand::Employee])
+            .build()
+            .unwrap();
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(request_params)
+            .id("a1b2c3d4")
+            .build()
+            .unwrap();
+
+        let result: BankInfoModel = request.send_request().await.unwrap();
+        let employee = result.employee.unwrap();
+
+        assert!(employee.is_expanded());
+        let employee = employee.into_expanded().unwrap();
+        assert_eq!(employee.id, "a3617eb4-dfe3-426f-921e-a65fc1661e10");
+        assert_eq!(employee.first_name, Some("Greg".to_string()));
+        let manager = employee.manager.unwrap();
+        assert!(!manager.is_expanded());
+        assert_eq!(manager.id(), Some("0048ea5b-911e-4dff-9364-92070dea62ff"));
+        m.assert()
+    }
+
     #[tokio::test]
     async fn test_it_return_error_on_failed_status() {
-        let config = HRISConfig::new("test", "test");
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
 
         let m = mock("GET", "/api/hris/v1/bank-info/not-found")
             .with_status(404)
@@ -310,14 +444,350 @@ mod tests {
             .build()
             .unwrap();
 
-        let result: Result<BankInfoModel, String> = request.send_request().await;
+        let result: Result<BankInfoModel, MergeError> = request.send_request().await;
 
         assert_eq!(result.is_err(), true);
         assert_eq!(
-            result.unwrap_err(),
+            result.unwrap_err().to_string(),
             "Request was not successfully status: 404 body: Some Body"
         );
 
         m.assert()
     }
+
+    #[tokio::test]
+    async fn it_returns_the_failed_response_as_a_typed_error() {
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let m = mock("GET", "/api/hris/v1/bank-info/missing")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body("Some Body")
+            .expect(1)
+            .create();
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .config(config)
+            .id("missing")
+            .build()
+            .unwrap();
+
+        let err = request.send_request().await.unwrap_err();
+
+        assert!(err.is_not_found());
+        match err {
+            MergeError::Status(response) => {
+                assert_eq!(response.status, 404);
+                assert_eq!(response.raw_body, "Some Body");
+            }
+            other => panic!("Expected a status error, got {:?}", other),
+        }
+
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_sends_requests_through_a_shared_client() {
+        let m = mock("GET", "/api/hris/v1/bank-info/shared-client")
+            .match_header("authorization", "Bearer clientKey")
+            .match_header("x-account-token", "clientToken")
+            .match_header("user-agent", "nightly-sync/1.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "{
+                  \"id\": \"shared-client\",
+                  \"remote_was_deleted\": false
+                }",
+            )
+            .expect(2)
+            .create();
+
+        let client = MergeClient::builder(
+            HRISConfig::new("clientKey", "clientToken").with_base_url(mockito::server_url()),
+        )
+        .user_agent("nightly-sync/1.0")
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .id("shared-client")
+            .build()
+            .unwrap();
+
+        let expected_model: BankInfoModel = BankInfoModelBuilder::default()
+            .id("shared-client")
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.send(&request).await.unwrap(), expected_model);
+        assert_eq!(client.send(&request).await.unwrap(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn test_it_requires_a_configuration() {
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .id("no-config")
+            .build()
+            .unwrap();
+
+        let result: Result<BankInfoModel, MergeError> = request.send_request().await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            MergeError::MissingConfiguration
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_follows_cursors_and_keeps_filters() {
+        let first = mock("GET", "/api/hris/v1/bank-info?bank_name=Paged&page_size=2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "{
+                  \"next\": \"page-2\",
+                  \"previous\": null,
+                  \"results\": [
+                    {\"id\": \"1\", \"remote_was_deleted\": false},
+                    {\"id\": \"2\", \"remote_was_deleted\": false}
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+        let second = mock(
+            "GET",
+            "/api/hris/v1/bank-info?bank_name=Paged&cursor=page-2&page_size=2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "{
+              \"next\": null,
+              \"previous\": \"page-1\",
+              \"results\": [
+                {\"id\": \"3\", \"remote_was_deleted\": false}
+              ]
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let client =
+            MergeClient::new(HRISConfig::new("test", "test").with_base_url(mockito::server_url()));
+
+        let request: GetRequest = GetRequestBuilder::default()
+            .params(
+                GetRequestParamsBuilder::default()
+                    .bank_name("Paged")
+                    .page_size(2)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let pages: Vec<GetRequestResponse> = client
+            .paginate(request.clone())
+            .pages()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].next, Some("page-2".to_string()));
+        assert_eq!(pages[1].results.len(), 1);
+
+        first.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn it_stops_when_the_next_cursor_repeats() {
+        let first = mock("GET", "/api/hris/v1/bank-info?bank_name=Stuck")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "{
+                  \"next\": \"page-2\",
+                  \"previous\": null,
+                  \"results\": [{\"id\": \"1\", \"remote_was_deleted\": false}]
+                }",
+            )
+            .expect(1)
+            .create();
+        let stuck = mock(
+            "GET",
+            "/api/hris/v1/bank-info?bank_name=Stuck&cursor=page-2",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "{
+              \"next\": \"page-2\",
+              \"previous\": null,
+              \"results\": [{\"id\": \"2\", \"remote_was_deleted\": false}]
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let client =
+            MergeClient::new(HRISConfig::new("test", "test").with_base_url(mockito::server_url()));
+
+        let request: GetRequest = GetRequestBuilder::default()
+            .params(
+                GetRequestParamsBuilder::default()
+                    .bank_name("Stuck")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let items: Vec<BankInfoModel> = client
+            .paginate(request)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2"]);
+
+        first.assert();
+        stuck.assert();
+    }
+
+    #[tokio::test]
+    async fn it_stops_streaming_items_at_the_limits() {
+        let m = mock("GET", "/api/hris/v1/bank-info?bank_name=Limited")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "{
+                  \"next\": \"page-2\",
+                  \"previous\": null,
+                  \"results\": [
+                    {\"id\": \"1\", \"remote_was_deleted\": false},
+                    {\"id\": \"2\", \"remote_was_deleted\": false},
+                    {\"id\": \"3\", \"remote_was_deleted\": false}
+                  ]
+                }",
+            )
+            .expect(2)
+            .create();
+
+        let client =
+            MergeClient::new(HRISConfig::new("test", "test").with_base_url(mockito::server_url()));
+
+        let request: GetRequest = GetRequestBuilder::default()
+            .params(
+                GetRequestParamsBuilder::default()
+                    .bank_name("Limited")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let items: Vec<BankInfoModel> = client
+            .paginate(request.clone())
+            .max_items(2)
+            .items()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+
+        let pages: Vec<GetRequestResponse> = client
+            .paginate(request)
+            .max_pages(1)
+            .pages()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 1);
+
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_retries_rate_limited_requests() {
+        let limited = mock("GET", "/api/hris/v1/bank-info/rate-limited")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .with_header("X-RateLimit-Remaining", "0")
+            .with_body("{\"detail\": \"Too many requests\"}")
+            .expect(1)
+            .create();
+        let ok = mock("GET", "/api/hris/v1/bank-info/rate-limited")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("X-RateLimit-Limit", "100")
+            .with_header("X-RateLimit-Remaining", "99")
+            .with_header("X-RateLimit-Reset", "60")
+            .with_body("{\"id\": \"rate-limited\", \"remote_was_deleted\": false}")
+            .expect(1)
+            .create();
+
+        let client =
+            MergeClient::new(HRISConfig::new("test", "test").with_base_url(mockito::server_url()));
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .id("rate-limited")
+            .build()
+            .unwrap();
+
+        let result: BankInfoModel = client.send(&request).await.unwrap();
+        assert_eq!(result.id, "rate-limited");
+
+        let rate_limit = client.rate_limit();
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(99));
+        assert_eq!(rate_limit.reset, Some(Duration::from_secs(60)));
+
+        limited.assert();
+        ok.assert();
+    }
+
+    #[tokio::test]
+    async fn test_it_gives_up_after_max_attempts() {
+        let m = mock("GET", "/api/hris/v1/bank-info/unavailable")
+            .with_status(503)
+            .with_body("Service Unavailable")
+            .expect(2)
+            .create();
+
+        let retry_policy: RetryPolicy = RetryPolicyBuilder::default()
+            .max_attempts(2u32)
+            .base_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        let client = MergeClient::builder(
+            HRISConfig::new("test", "test").with_base_url(mockito::server_url()),
+        )
+        .retry_policy(retry_policy)
+        .build()
+        .unwrap();
+
+        let request: GetRequestById = GetRequestByIdBuilder::default()
+            .id("unavailable")
+            .build()
+            .unwrap();
+
+        let err = client.send(&request).await.unwrap_err();
+        assert_eq!(err.status(), Some(503));
+
+        m.assert()
+    }
 }
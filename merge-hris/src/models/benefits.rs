@@ -1,4 +1,11 @@
 use crate::configuration::HRISConfig;
+use crate::models::employees::EmployeeModel;
+use crate::models::employer_benefits::EmployerBenefitModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
 use serde::{Deserialize, Serialize};
 
 use merge_proc_macros::{generate_url_params, send_request};
@@ -10,7 +17,7 @@ pub struct BenefitModel {
     #[builder(setter(into, strip_option), default)]
     pub remote_id: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub employee: Option<String>,
+    pub employee: Option<Expandable<EmployeeModel>>,
     #[builder(setter(into, strip_option), default)]
     pub provider_name: Option<String>,
     #[builder(setter(into, strip_option), default)]
@@ -20,33 +27,34 @@ pub struct BenefitModel {
     #[builder(setter(into, strip_option), default)]
     pub company_contribution: Option<f64>,
     #[builder(setter(into, strip_option), default)]
-    pub remote_data: Option<Vec<RemoteData>>,
-    pub remote_was_deleted: bool,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
-#[builder(setter(into))]
-pub struct RemoteData {
+    pub start_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_date: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub path: Option<String>,
+    pub employer_benefit: Option<Expandable<EmployerBenefitModel>>,
     #[builder(setter(into, strip_option), default)]
-    pub data: Option<Vec<String>>,
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
 #[builder(setter(into))]
-#[send_request(service="hris", model="benefits", return_type=GetRequestResponse)]
+#[send_request(service="hris", model="benefits", return_type=Paginated<BenefitModel>)]
 pub struct GetRequest {
-    pub config: HRISConfig,
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
     #[builder(setter(into, strip_option), default)]
     pub params: Option<GetRequestParams>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct GetRequestResponse {
-    pub next: Option<String>,
-    pub previous: Option<String>,
-    pub results: Vec<BenefitModel>,
+pub type GetRequestResponse = Paginated<BenefitModel>;
+
+expand_enum! {
+    /// The relations of `BenefitModel` that `expand` can return in full.
+    pub enum BenefitExpand {
+        Employee => "employee",
+        EmployerBenefit => "employer_benefit",
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
@@ -54,23 +62,23 @@ pub struct GetRequestResponse {
 #[generate_url_params]
 pub struct GetRequestParams {
     #[builder(setter(into, strip_option), default)]
-    pub created_after: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
-    pub created_before: Option<String>,
+    pub created_before: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
     pub cursor: Option<String>,
     #[builder(setter(into, strip_option), default)]
     pub employee_id: Option<String>,
     #[builder(setter(into, strip_option), default)]
-    pub expand: Option<String>,
+    pub expand: Option<Vec<BenefitExpand>>,
     #[builder(setter(into, strip_option), default)]
     pub include_deleted_data: Option<String>,
     #[builder(setter(into, strip_option), default)]
     pub include_remote_data: Option<bool>,
     #[builder(setter(into, strip_option), default)]
-    pub modified_after: Option<String>,
+    pub modified_after: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
-    pub modified_before: Option<String>,
+    pub modified_before: Option<DateTime<Utc>>,
     #[builder(setter(into, strip_option), default)]
     pub page_size: Option<i32>,
     #[builder(setter(into, strip_option), default)]
@@ -81,7 +89,8 @@ pub struct GetRequestParams {
 #[builder(setter(into))]
 #[send_request(service="hris", model="benefits", return_type=BenefitModel)]
 pub struct GetRequestById {
-    pub config: HRISConfig,
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
     pub id: String,
     #[builder(setter(into, strip_option), default)]
     pub params: Option<GetRequestByIdParams>,
@@ -92,7 +101,7 @@ pub struct GetRequestById {
 #[generate_url_params]
 pub struct GetRequestByIdParams {
     #[builder(setter(into, strip_option), default)]
-    pub expand: Option<String>,
+    pub expand: Option<Vec<BenefitExpand>>,
     #[builder(setter(into, strip_option), default)]
     pub include_remote_data: Option<bool>,
     #[builder(setter(into, strip_option), default)]
@@ -100,8 +109,10 @@ pub struct GetRequestByIdParams {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::get_first)]
 mod tests {
     use super::*;
+    use merge_config::remote::RemoteDataBuilder;
     use mockito::mock;
 
     #[tokio::test]
@@ -122,6 +133,9 @@ mod tests {
                                   \"benefit_plan_type\": \"MEDICAL\",
                                   \"employee_contribution\": 23.65,
                                   \"company_contribution\": 150,
+                                  \"start_date\": \"2020-11-10T00:00:00Z\",
+                                  \"end_date\": \"2021-11-10T00:00:00Z\",
+                                  \"employer_benefit\": \"025fjlc6-6000-430a-848e-aafacbadf4f9\",
                                   \"remote_data\": [
                                     {
                                       \"path\": \"/benefits\",
@@ -151,12 +165,15 @@ mod tests {
             .benefit_plan_type("MEDICAL")
             .employee_contribution(23.65)
             .company_contribution(150)
+            .start_date("2020-11-10T00:00:00Z")
+            .end_date("2021-11-10T00:00:00Z")
+            .employer_benefit("025fjlc6-6000-430a-848e-aafacbadf4f9")
             .remote_data(vec![expected_remote_data])
             .remote_was_deleted(true)
             .build()
             .unwrap();
 
-        let config = HRISConfig::new("test", "test");
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
 
         let params: GetRequestParams = GetRequestParamsBuilder::default()
             .include_remote_data(true)
@@ -202,6 +219,9 @@ mod tests {
               \"benefit_plan_type\": \"MEDICAL\",
               \"employee_contribution\": 23.65,
               \"company_contribution\": 150,
+              \"start_date\": \"2020-11-10T00:00:00Z\",
+              \"end_date\": \"2021-11-10T00:00:00Z\",
+              \"employer_benefit\": \"025fjlc6-6000-430a-848e-aafacbadf4f9\",
               \"remote_data\": [
                 {
                   \"path\": \"/benefits\",
@@ -230,12 +250,15 @@ mod tests {
             .benefit_plan_type("MEDICAL")
             .employee_contribution(23.65)
             .company_contribution(150)
+            .start_date("2020-11-10T00:00:00Z")
+            .end_date("2021-11-10T00:00:00Z")
+            .employer_benefit("025fjlc6-6000-430a-848e-aafacbadf4f9")
             .remote_data(vec![expected_remote_data])
             .remote_was_deleted(true)
             .build()
             .unwrap();
 
-        let config = HRISConfig::new("test", "test");
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
 
         let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
             .include_remote_data(true)
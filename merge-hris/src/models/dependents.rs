@@ -0,0 +1,281 @@
+use crate::configuration::HRISConfig;
+use chrono::{DateTime, Utc};
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct DependentModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub middle_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub relationship: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub date_of_birth: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub gender: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub phone_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub home_location: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_student: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub is_disabled: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub ssn: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="dependents", return_type=Paginated<DependentModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_sensitive_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<DependentModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="dependents", return_type=DependentModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_sensitive_fields: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/hris/v1/dependents?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"first_name\": \"Greg\",
+                      \"middle_name\": \"A\",
+                      \"last_name\": \"Hirsch\",
+                      \"relationship\": \"CHILD\",
+                      \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+                      \"date_of_birth\": \"1990-11-10T00:00:00Z\",
+                      \"gender\": \"MALE\",
+                      \"phone_number\": \"+1111111111\",
+                      \"home_location\": \"45607da1-41ba-40a9-93a7-7e50519a7c3b\",
+                      \"is_student\": true,
+                      \"is_disabled\": false,
+                      \"ssn\": \"1234567890\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/dependents\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = DependentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .first_name("Greg")
+            .middle_name("A")
+            .last_name("Hirsch")
+            .relationship("CHILD")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .date_of_birth("1990-11-10T00:00:00Z")
+            .gender("MALE")
+            .phone_number("+1111111111")
+            .home_location("45607da1-41ba-40a9-93a7-7e50519a7c3b")
+            .is_student(true)
+            .is_disabled(false)
+            .ssn("1234567890")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/dependents")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/dependents/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"first_name\": \"Greg\",
+              \"middle_name\": \"A\",
+              \"last_name\": \"Hirsch\",
+              \"relationship\": \"CHILD\",
+              \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+              \"date_of_birth\": \"1990-11-10T00:00:00Z\",
+              \"gender\": \"MALE\",
+              \"phone_number\": \"+1111111111\",
+              \"home_location\": \"45607da1-41ba-40a9-93a7-7e50519a7c3b\",
+              \"is_student\": true,
+              \"is_disabled\": false,
+              \"ssn\": \"1234567890\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/dependents\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = DependentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .first_name("Greg")
+            .middle_name("A")
+            .last_name("Hirsch")
+            .relationship("CHILD")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .date_of_birth("1990-11-10T00:00:00Z")
+            .gender("MALE")
+            .phone_number("+1111111111")
+            .home_location("45607da1-41ba-40a9-93a7-7e50519a7c3b")
+            .is_student(true)
+            .is_disabled(false)
+            .ssn("1234567890")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/dependents")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: DependentModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
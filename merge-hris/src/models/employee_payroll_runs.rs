@@ -0,0 +1,459 @@
+use crate::configuration::HRISConfig;
+use crate::models::employees::EmployeeModel;
+use crate::models::payroll_runs::PayrollRunModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EmployeePayrollRunModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee: Option<Expandable<EmployeeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub payroll_run: Option<Expandable<PayrollRunModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub gross_pay: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub net_pay: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub check_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub earnings: Option<Vec<Earning>>,
+    #[builder(setter(into, strip_option), default)]
+    pub deductions: Option<Vec<Deduction>>,
+    #[builder(setter(into, strip_option), default)]
+    pub taxes: Option<Vec<Tax>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Earning {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_payroll_run: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub amount: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub r#type: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Deduction {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_payroll_run: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_deduction: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub company_deduction: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct Tax {
+    #[builder(setter(into, strip_option), default)]
+    pub id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_payroll_run: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub amount: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub employer_tax: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employee-payroll-runs", return_type=Paginated<EmployeePayrollRunModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `EmployeePayrollRunModel` that `expand` can return in full.
+    pub enum EmployeePayrollRunExpand {
+        Employee => "employee",
+        PayrollRun => "payroll_run",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ended_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub ended_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmployeePayrollRunExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub payroll_run_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_before: Option<DateTime<Utc>>,
+}
+
+pub type GetRequestResponse = Paginated<EmployeePayrollRunModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employee-payroll-runs", return_type=EmployeePayrollRunModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmployeePayrollRunExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employee-payroll-runs?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+                      \"payroll_run\": \"4771ed8e-d5ad-4407-998a-335e73e40a3a\",
+                      \"gross_pay\": 1342.67,
+                      \"net_pay\": 865.78,
+                      \"start_date\": \"2020-11-08T00:00:00Z\",
+                      \"end_date\": \"2020-11-15T00:00:00Z\",
+                      \"check_date\": \"2020-11-16T00:00:00Z\",
+                      \"earnings\": [
+                        {
+                          \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                          \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                          \"amount\": 1002.34,
+                          \"type\": \"SALARY\"
+                        }
+                      ],
+                      \"deductions\": [
+                        {
+                          \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                          \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                          \"name\": \"Social Security\",
+                          \"employee_deduction\": 34.54,
+                          \"company_deduction\": 78.78
+                        }
+                      ],
+                      \"taxes\": [
+                        {
+                          \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                          \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                          \"name\": \"California State Income Tax\",
+                          \"amount\": 100.25,
+                          \"employer_tax\": false
+                        }
+                      ],
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/employee-payroll-runs\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = EmployeePayrollRunModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .payroll_run("4771ed8e-d5ad-4407-998a-335e73e40a3a")
+            .gross_pay(1342.67)
+            .net_pay(865.78)
+            .start_date("2020-11-08T00:00:00Z")
+            .end_date("2020-11-15T00:00:00Z")
+            .check_date("2020-11-16T00:00:00Z")
+            .earnings(vec![EarningBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .amount(1002.34)
+                .r#type("SALARY")
+                .build()
+                .unwrap()])
+            .deductions(vec![DeductionBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .name("Social Security")
+                .employee_deduction(34.54)
+                .company_deduction(78.78)
+                .build()
+                .unwrap()])
+            .taxes(vec![TaxBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .name("California State Income Tax")
+                .amount(100.25)
+                .employer_tax(false)
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employee-payroll-runs")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employee-payroll-runs/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+              \"payroll_run\": \"4771ed8e-d5ad-4407-998a-335e73e40a3a\",
+              \"gross_pay\": 1342.67,
+              \"net_pay\": 865.78,
+              \"start_date\": \"2020-11-08T00:00:00Z\",
+              \"end_date\": \"2020-11-15T00:00:00Z\",
+              \"check_date\": \"2020-11-16T00:00:00Z\",
+              \"earnings\": [
+                {
+                  \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                  \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                  \"amount\": 1002.34,
+                  \"type\": \"SALARY\"
+                }
+              ],
+              \"deductions\": [
+                {
+                  \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                  \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                  \"name\": \"Social Security\",
+                  \"employee_deduction\": 34.54,
+                  \"company_deduction\": 78.78
+                }
+              ],
+              \"taxes\": [
+                {
+                  \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                  \"employee_payroll_run\": \"7af16b2f-74e0-4c70-9f84-fe0a07b8aadb\",
+                  \"name\": \"California State Income Tax\",
+                  \"amount\": 100.25,
+                  \"employer_tax\": false
+                }
+              ],
+              \"remote_data\": [
+                {
+                  \"path\": \"/employee-payroll-runs\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = EmployeePayrollRunModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .payroll_run("4771ed8e-d5ad-4407-998a-335e73e40a3a")
+            .gross_pay(1342.67)
+            .net_pay(865.78)
+            .start_date("2020-11-08T00:00:00Z")
+            .end_date("2020-11-15T00:00:00Z")
+            .check_date("2020-11-16T00:00:00Z")
+            .earnings(vec![EarningBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .amount(1002.34)
+                .r#type("SALARY")
+                .build()
+                .unwrap()])
+            .deductions(vec![DeductionBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .name("Social Security")
+                .employee_deduction(34.54)
+                .company_deduction(78.78)
+                .build()
+                .unwrap()])
+            .taxes(vec![TaxBuilder::default()
+                .id("b80bb774-0288-4da1-9201-890375a60c8f")
+                .employee_payroll_run("7af16b2f-74e0-4c70-9f84-fe0a07b8aadb")
+                .name("California State Income Tax")
+                .amount(100.25)
+                .employer_tax(false)
+                .build()
+                .unwrap()])
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employee-payroll-runs")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: EmployeePayrollRunModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_by_payroll_run() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employee-payroll-runs?ended_before=2020-11-30T00%3A00%3A00Z&payroll_run_id=4771ed8e&started_after=2020-11-01T00%3A00%3A00Z",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .ended_before(Utc.with_ymd_and_hms(2020, 11, 30, 0, 0, 0).unwrap())
+            .payroll_run_id("4771ed8e")
+            .started_after(Utc.with_ymd_and_hms(2020, 11, 1, 0, 0, 0).unwrap())
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+}
@@ -0,0 +1,577 @@
+use crate::configuration::HRISConfig;
+use crate::models::companies::CompanyModel;
+use crate::models::employments::EmploymentModel;
+use crate::models::groups::GroupModel;
+use crate::models::locations::LocationModel;
+use crate::models::pay_groups::PayGroupModel;
+use crate::models::teams::TeamModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EmployeeModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub company: Option<Expandable<CompanyModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub preferred_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub display_full_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub username: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub groups: Option<Vec<Expandable<GroupModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub work_email: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub personal_email: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub mobile_phone_number: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employments: Option<Vec<Expandable<EmploymentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub home_location: Option<Expandable<LocationModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub work_location: Option<Expandable<LocationModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub manager: Option<Expandable<EmployeeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub team: Option<Expandable<TeamModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_group: Option<Expandable<PayGroupModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub ssn: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub gender: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ethnicity: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub marital_status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub date_of_birth: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub hire_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employment_status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub termination_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub avatar: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub custom_fields: Option<serde_json::Value>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employees", return_type=Paginated<EmployeeModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `EmployeeModel` that `expand` can return in full.
+    pub enum EmployeeExpand {
+        Company => "company",
+        Groups => "groups",
+        Employments => "employments",
+        HomeLocation => "home_location",
+        WorkLocation => "work_location",
+        Manager => "manager",
+        Team => "team",
+        PayGroup => "pay_group",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub company_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub display_full_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employment_status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmployeeExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub groups: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub home_location_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_sensitive_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub manager_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_group_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub personal_email: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub started_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub team_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub terminated_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub terminated_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub work_email: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub work_location_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<EmployeeModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employees", return_type=EmployeeModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmployeeExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_sensitive_fields: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/hris/v1/employees?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"employee_number\": \"2\",
+                      \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+                      \"first_name\": \"Greg\",
+                      \"last_name\": \"Hirsch\",
+                      \"preferred_name\": \"Greg the egg\",
+                      \"display_full_name\": \"Cousin Greg Hirsch\",
+                      \"username\": \"cousingreg\",
+                      \"groups\": [
+                        \"1471e4e0-5a4d-495d-953c-c867fe317314\"
+                      ],
+                      \"work_email\": \"jane@example.com\",
+                      \"personal_email\": \"jane@example.com\",
+                      \"mobile_phone_number\": \"+1234567890\",
+                      \"employments\": [
+                        \"b4d58fc4-cbdc-4db0-92ca-5491caecfb93\"
+                      ],
+                      \"home_location\": \"45607da1-41ba-40a9-93a7-7e50519a7c3b\",
+                      \"work_location\": \"1c91eec9-adaa-47b4-9ade-1cda8a1bf802\",
+                      \"manager\": \"1d0258c2-440a-4d19-9716-292b231e3190\",
+                      \"team\": \"f894427c-c1c5-41f7-9da4-9605ef8b112f\",
+                      \"pay_group\": \"572b22c5-4e44-4320-9690-bf3f7ec5e04a\",
+                      \"ssn\": \"1234567890\",
+                      \"gender\": \"MALE\",
+                      \"ethnicity\": \"AMERICAN_INDIAN_OR_ALASKA_NATIVE\",
+                      \"marital_status\": \"SINGLE\",
+                      \"date_of_birth\": \"2021-10-01\",
+                      \"hire_date\": \"2021-10-01\",
+                      \"start_date\": \"2021-10-01\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"employment_status\": \"ACTIVE\",
+                      \"termination_date\": \"2021-10-01\",
+                      \"avatar\": \"http://alturl.com/h2h8m\",
+                      \"custom_fields\": {
+                        \"key\": \"value\"
+                      },
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/employees\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = EmployeeModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee_number("2")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .first_name("Greg")
+            .last_name("Hirsch")
+            .preferred_name("Greg the egg")
+            .display_full_name("Cousin Greg Hirsch")
+            .username("cousingreg")
+            .groups(vec![Expandable::from(
+                "1471e4e0-5a4d-495d-953c-c867fe317314",
+            )])
+            .work_email("jane@example.com")
+            .personal_email("jane@example.com")
+            .mobile_phone_number("+1234567890")
+            .employments(vec![Expandable::from(
+                "b4d58fc4-cbdc-4db0-92ca-5491caecfb93",
+            )])
+            .home_location("45607da1-41ba-40a9-93a7-7e50519a7c3b")
+            .work_location("1c91eec9-adaa-47b4-9ade-1cda8a1bf802")
+            .manager("1d0258c2-440a-4d19-9716-292b231e3190")
+            .team("f894427c-c1c5-41f7-9da4-9605ef8b112f")
+            .pay_group("572b22c5-4e44-4320-9690-bf3f7ec5e04a")
+            .ssn("1234567890")
+            .gender("MALE")
+            .ethnicity("AMERICAN_INDIAN_OR_ALASKA_NATIVE")
+            .marital_status("SINGLE")
+            .date_of_birth("2021-10-01")
+            .hire_date("2021-10-01")
+            .start_date("2021-10-01")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .employment_status("ACTIVE")
+            .termination_date("2021-10-01")
+            .avatar("http://alturl.com/h2h8m")
+            .custom_fields(serde_json::json!({"key": "value"}))
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employees")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employees/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"employee_number\": \"2\",
+              \"company\": \"93c731f1-c3a8-4ef0-9cd5-4d044c379eaa\",
+              \"first_name\": \"Greg\",
+              \"last_name\": \"Hirsch\",
+              \"preferred_name\": \"Greg the egg\",
+              \"display_full_name\": \"Cousin Greg Hirsch\",
+              \"username\": \"cousingreg\",
+              \"groups\": [
+                \"1471e4e0-5a4d-495d-953c-c867fe317314\"
+              ],
+              \"work_email\": \"jane@example.com\",
+              \"personal_email\": \"jane@example.com\",
+              \"mobile_phone_number\": \"+1234567890\",
+              \"employments\": [
+                \"b4d58fc4-cbdc-4db0-92ca-5491caecfb93\"
+              ],
+              \"home_location\": \"45607da1-41ba-40a9-93a7-7e50519a7c3b\",
+              \"work_location\": \"1c91eec9-adaa-47b4-9ade-1cda8a1bf802\",
+              \"manager\": \"1d0258c2-440a-4d19-9716-292b231e3190\",
+              \"team\": \"f894427c-c1c5-41f7-9da4-9605ef8b112f\",
+              \"pay_group\": \"572b22c5-4e44-4320-9690-bf3f7ec5e04a\",
+              \"ssn\": \"1234567890\",
+              \"gender\": \"MALE\",
+              \"ethnicity\": \"AMERICAN_INDIAN_OR_ALASKA_NATIVE\",
+              \"marital_status\": \"SINGLE\",
+              \"date_of_birth\": \"2021-10-01\",
+              \"hire_date\": \"2021-10-01\",
+              \"start_date\": \"2021-10-01\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"employment_status\": \"ACTIVE\",
+              \"termination_date\": \"2021-10-01\",
+              \"avatar\": \"http://alturl.com/h2h8m\",
+              \"custom_fields\": {
+                \"key\": \"value\"
+              },
+              \"remote_data\": [
+                {
+                  \"path\": \"/employees\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = EmployeeModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee_number("2")
+            .company("93c731f1-c3a8-4ef0-9cd5-4d044c379eaa")
+            .first_name("Greg")
+            .last_name("Hirsch")
+            .preferred_name("Greg the egg")
+            .display_full_name("Cousin Greg Hirsch")
+            .username("cousingreg")
+            .groups(vec![Expandable::from(
+                "1471e4e0-5a4d-495d-953c-c867fe317314",
+            )])
+            .work_email("jane@example.com")
+            .personal_email("jane@example.com")
+            .mobile_phone_number("+1234567890")
+            .employments(vec![Expandable::from(
+                "b4d58fc4-cbdc-4db0-92ca-5491caecfb93",
+            )])
+            .home_location("45607da1-41ba-40a9-93a7-7e50519a7c3b")
+            .work_location("1c91eec9-adaa-47b4-9ade-1cda8a1bf802")
+            .manager("1d0258c2-440a-4d19-9716-292b231e3190")
+            .team("f894427c-c1c5-41f7-9da4-9605ef8b112f")
+            .pay_group("572b22c5-4e44-4320-9690-bf3f7ec5e04a")
+            .ssn("1234567890")
+            .gender("MALE")
+            .ethnicity("AMERICAN_INDIAN_OR_ALASKA_NATIVE")
+            .marital_status("SINGLE")
+            .date_of_birth("2021-10-01")
+            .hire_date("2021-10-01")
+            .start_date("2021-10-01")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .employment_status("ACTIVE")
+            .termination_date("2021-10-01")
+            .avatar("http://alturl.com/h2h8m")
+            .custom_fields(serde_json::json!({"key": "value"}))
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employees")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: EmployeeModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_employees() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employees?employment_status=ACTIVE&manager_id=1d0258c2&started_after=2021-01-01T00%3A00%3A00Z&team_id=f894427c&work_email=greg%2Bwork%40waystar-royco.com",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .employment_status("ACTIVE")
+            .manager_id("1d0258c2")
+            .started_after(Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap())
+            .team_id("f894427c")
+            .work_email("greg+work@waystar-royco.com")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_expand_relations() {
+        let m = mock("GET", "/api/hris/v1/employees?expand=manager,team,groups")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": null,
+                  \"previous\": null,
+                  \"results\": [
+                    {
+                      \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                      \"manager\": {
+                        \"id\": \"0048ea5b-911e-4dff-9364-92070dea62ff\",
+                        \"remote_was_deleted\": false
+                      },
+                      \"team\": {
+                        \"id\": \"13a1f4bd-2a68-4a6a-b0d2-8e0dd7f0e8a3\",
+                        \"name\": \"Platform\",
+                        \"remote_was_deleted\": false
+                      },
+                      \"groups\": [
+                        {
+                          \"id\": \"1471e4e0-5a4d-495d-953c-c867fe317314\",
+                          \"name\": \"Engineering\",
+                          \"remote_was_deleted\": false
+                        }
+                      ],
+                      \"pay_group\": \"b1a2c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d\",
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .expand(vec![
+                EmployeeExpand::Manager,
+                EmployeeExpand::Team,
+                EmployeeExpand::Groups,
+            ])
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+        let employee = &response.results[0];
+
+        let manager = employee.manager.as_ref().and_then(Expandable::expanded);
+        assert_eq!(
+            manager.map(|manager| manager.id.as_str()),
+            Some("0048ea5b-911e-4dff-9364-92070dea62ff")
+        );
+        let team = employee.team.as_ref().and_then(Expandable::expanded);
+        assert_eq!(team.and_then(|team| team.name.as_deref()), Some("Platform"));
+        let groups = employee.groups.as_ref().unwrap();
+        assert!(groups.iter().all(Expandable::is_expanded));
+        assert_eq!(
+            employee.pay_group.as_ref().and_then(Expandable::id),
+            Some("b1a2c3d4-5e6f-4a7b-8c9d-0e1f2a3b4c5d")
+        );
+        m.assert()
+    }
+}
@@ -0,0 +1,289 @@
+use crate::configuration::HRISConfig;
+use crate::models::employees::EmployeeModel;
+use crate::models::pay_groups::PayGroupModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct EmploymentModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee: Option<Expandable<EmployeeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub job_title: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_rate: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_period: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_frequency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_currency: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub pay_group: Option<Expandable<PayGroupModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub flsa_status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub effective_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employment_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employments", return_type=Paginated<EmploymentModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `EmploymentModel` that `expand` can return in full.
+    pub enum EmploymentExpand {
+        Employee => "employee",
+        PayGroup => "pay_group",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmploymentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub order_by: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<EmploymentModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="employments", return_type=EmploymentModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<EmploymentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/hris/v1/employments?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+                      \"job_title\": \"Executive Assistant to Tom Wambsgans\",
+                      \"pay_rate\": 1250.5,
+                      \"pay_period\": \"WEEK\",
+                      \"pay_frequency\": \"WEEKLY\",
+                      \"pay_currency\": \"USD\",
+                      \"pay_group\": \"572b22c5-4e44-4320-9690-bf3f7ec5e04a\",
+                      \"flsa_status\": \"EXEMPT\",
+                      \"effective_date\": \"2021-10-01\",
+                      \"employment_type\": \"FULL_TIME\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/employments\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = EmploymentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .job_title("Executive Assistant to Tom Wambsgans")
+            .pay_rate(1250.5)
+            .pay_period("WEEK")
+            .pay_frequency("WEEKLY")
+            .pay_currency("USD")
+            .pay_group("572b22c5-4e44-4320-9690-bf3f7ec5e04a")
+            .flsa_status("EXEMPT")
+            .effective_date("2021-10-01")
+            .employment_type("FULL_TIME")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/employments/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+              \"job_title\": \"Executive Assistant to Tom Wambsgans\",
+              \"pay_rate\": 1250.5,
+              \"pay_period\": \"WEEK\",
+              \"pay_frequency\": \"WEEKLY\",
+              \"pay_currency\": \"USD\",
+              \"pay_group\": \"572b22c5-4e44-4320-9690-bf3f7ec5e04a\",
+              \"flsa_status\": \"EXEMPT\",
+              \"effective_date\": \"2021-10-01\",
+              \"employment_type\": \"FULL_TIME\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/employments\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = EmploymentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .job_title("Executive Assistant to Tom Wambsgans")
+            .pay_rate(1250.5)
+            .pay_period("WEEK")
+            .pay_frequency("WEEKLY")
+            .pay_currency("USD")
+            .pay_group("572b22c5-4e44-4320-9690-bf3f7ec5e04a")
+            .flsa_status("EXEMPT")
+            .effective_date("2021-10-01")
+            .employment_type("FULL_TIME")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/employments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: EmploymentModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+}
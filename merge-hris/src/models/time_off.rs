@@ -0,0 +1,531 @@
+use crate::configuration::HRISConfig;
+use crate::models::employees::EmployeeModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TimeOffModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee: Option<Expandable<EmployeeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub approver: Option<Expandable<EmployeeModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_note: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub units: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub amount: Option<f64>,
+    #[builder(setter(into, strip_option), default)]
+    pub request_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub start_time: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub end_time: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="time-off", return_type=Paginated<TimeOffModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `TimeOffModel` that `expand` can return in full.
+    pub enum TimeOffExpand {
+        Employee => "employee",
+        Approver => "approver",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub approver_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub employee_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TimeOffExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub request_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<TimeOffModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="time-off", return_type=TimeOffModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TimeOffExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+/// The fields accepted when creating time off. Which of them a linked account's integration
+/// actually needs can be discovered with `MetaPostRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TimeOffRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub employee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub approver: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub employee_note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub units: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub request_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="time-off", return_type=WriteResponse<TimeOffModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+    pub model: TimeOffRequest,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+pub type CreateRequestResponse = WriteResponse<TimeOffModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="hris", model="time-off/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<HRISConfig>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/hris/v1/time-off?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+                      \"approver\": \"d51f92a7-3854-4bc9-98de-4e4e1aac36e6\",
+                      \"status\": \"APPROVED\",
+                      \"employee_note\": \"Moving into the new apartment Kendall Roy gave me!\",
+                      \"units\": \"DAYS\",
+                      \"amount\": 3.0,
+                      \"request_type\": \"VACATION\",
+                      \"start_time\": \"2020-11-10T00:00:00Z\",
+                      \"end_time\": \"2020-11-17T00:00:00Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/time-off\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = TimeOffModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .approver("d51f92a7-3854-4bc9-98de-4e4e1aac36e6")
+            .status("APPROVED")
+            .employee_note("Moving into the new apartment Kendall Roy gave me!")
+            .units("DAYS")
+            .amount(3.0)
+            .request_type("VACATION")
+            .start_time("2020-11-10T00:00:00Z")
+            .end_time("2020-11-17T00:00:00Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/time-off")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/time-off/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"employee\": \"fa547353-0e4d-4a5a-9e1e-b53d2fedb10c\",
+              \"approver\": \"d51f92a7-3854-4bc9-98de-4e4e1aac36e6\",
+              \"status\": \"APPROVED\",
+              \"employee_note\": \"Moving into the new apartment Kendall Roy gave me!\",
+              \"units\": \"DAYS\",
+              \"amount\": 3.0,
+              \"request_type\": \"VACATION\",
+              \"start_time\": \"2020-11-10T00:00:00Z\",
+              \"end_time\": \"2020-11-17T00:00:00Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/time-off\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = TimeOffModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .employee("fa547353-0e4d-4a5a-9e1e-b53d2fedb10c")
+            .approver("d51f92a7-3854-4bc9-98de-4e4e1aac36e6")
+            .status("APPROVED")
+            .employee_note("Moving into the new apartment Kendall Roy gave me!")
+            .units("DAYS")
+            .amount(3.0)
+            .request_type("VACATION")
+            .start_time("2020-11-10T00:00:00Z")
+            .end_time("2020-11-17T00:00:00Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/time-off")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: TimeOffModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_time_off() {
+        let m = mock(
+            "GET",
+            "/api/hris/v1/time-off?approver_id=9efbc633&request_type=VACATION&status=APPROVED",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .approver_id("9efbc633")
+            .request_type("VACATION")
+            .status("APPROVED")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_time_off() {
+        let m = mock("POST", "/api/hris/v1/time-off?is_debug_mode=true")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "employee": "d2f972d0-2526-434b-9409-4c3b468e08f0",
+                    "units": "DAYS",
+                    "amount": 3.0,
+                    "request_type": "VACATION",
+                    "start_time": "2020-11-10T00:00:00Z",
+                    "end_time": "2020-11-17T00:00:00Z"
+                }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"91b2b905-e866-40c8-8be2-efe53827a0aa\",
+                    \"employee\": \"d2f972d0-2526-434b-9409-4c3b468e08f0\",
+                    \"status\": \"REQUESTED\",
+                    \"units\": \"DAYS\",
+                    \"amount\": 3,
+                    \"request_type\": \"VACATION\",
+                    \"start_time\": \"2020-11-10T00:00:00Z\",
+                    \"end_time\": \"2020-11-17T00:00:00Z\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [
+                    {
+                      \"source\": {
+                        \"pointer\": \"string\"
+                      },
+                      \"title\": \"Unrecognized Field\",
+                      \"detail\": \"An unrecognized field, age, was passed in with request data.\",
+                      \"problem_type\": \"UNRECOGNIZED_FIELD\"
+                    }
+                  ],
+                  \"errors\": [],
+                  \"logs\": [
+                    {
+                      \"log_id\": \"99433219-8017-4acd-bb3c-ceb23d663832\",
+                      \"dashboard_view\": \"https://app.merge.dev/logs/99433219-8017-4acd-bb3c-ceb23d663832\",
+                      \"log_summary\": {
+                        \"url\": \"www.exampleintegration.com/api/v1/exampleapi\",
+                        \"method\": \"POST\",
+                        \"status_code\": 201
+                      }
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let time_off: TimeOffRequest = TimeOffRequestBuilder::default()
+            .employee("d2f972d0-2526-434b-9409-4c3b468e08f0")
+            .units("DAYS")
+            .amount(3.0)
+            .request_type("VACATION")
+            .start_time("2020-11-10T00:00:00Z")
+            .end_time("2020-11-17T00:00:00Z")
+            .build()
+            .unwrap();
+
+        let params: CreateRequestParams = CreateRequestParamsBuilder::default()
+            .is_debug_mode(true)
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(time_off)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: CreateRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "91b2b905-e866-40c8-8be2-efe53827a0aa");
+        assert_eq!(response.model.status, Some("REQUESTED".to_string()));
+        assert_eq!(response.warnings.len(), 1);
+        assert_eq!(response.warnings[0].problem_type, "UNRECOGNIZED_FIELD");
+        assert!(response.errors.is_empty());
+        assert_eq!(response.logs.unwrap()[0].log_summary.status_code, 201);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/hris/v1/time-off/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [\"employee\", \"request_type\"]
+                      }
+                    }
+                  },
+                  \"status\": {
+                    \"linked_account_status\": \"COMPLETE\",
+                    \"can_make_request\": true
+                  },
+                  \"has_conditional_params\": false,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = HRISConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response: MetaResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"],
+            serde_json::json!(["employee", "request_type"])
+        );
+        assert!(response.status.unwrap().can_make_request);
+        assert!(!response.has_conditional_params);
+        m.assert()
+    }
+}
@@ -1,9 +1,14 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 
+use proc_macro2::{Ident, Span};
 use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, LitStr, Type};
+use syn::{
+    parse_macro_input, Attribute, Data, DataStruct, DeriveInput, Fields, GenericArgument, Lit,
+    LitStr, Meta, MetaNameValue, NestedMeta, PathArguments, Type,
+};
 
 macro_rules! derive_parse {(
     @derive_only
@@ -50,10 +55,12 @@ macro_rules! derive_parse {(
             }
             Ok(Self {
                 $(
-                    $field_name: $field_name.ok_or_else(|| ::syn::Error::new(
-                        ::proc_macro2::Span::call_site(),
-                        ::core::concat!("Missing key `", ::core::stringify!($field_name), "`"),
-                    ))?,
+                    $field_name: $field_name
+                        .or_else(<$FieldTy as ArgDefault>::arg_default)
+                        .ok_or_else(|| ::syn::Error::new(
+                            ::proc_macro2::Span::call_site(),
+                            ::core::concat!("Missing key `", ::core::stringify!($field_name), "`"),
+                        ))?,
                 )*
             })
         }
@@ -66,45 +73,131 @@ macro_rules! derive_parse {(
     derive_parse! { @derive_only  $( #[$attr] )* $pub struct $($rest)* }
 )}
 
+/// The value used by `derive_parse!` when a key is left out. `None` makes the key required.
+trait ArgDefault: Sized {
+    fn arg_default() -> Option<Self> {
+        None
+    }
+}
+
+impl ArgDefault for LitStr {}
+
+impl ArgDefault for Type {}
+
+/// The HTTP method of a request, e.g. `method="POST"`. Defaults to `GET`.
+struct HttpMethod(Ident);
+
+impl Parse for HttpMethod {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let method: LitStr = input.parse()?;
+        match method.value().to_uppercase().as_str() {
+            name @ ("GET" | "POST" | "PATCH" | "PUT" | "DELETE") => {
+                Ok(HttpMethod(Ident::new(name, method.span())))
+            }
+            _ => Err(syn::Error::new(
+                method.span(),
+                "Expected one of GET, POST, PATCH, PUT or DELETE",
+            )),
+        }
+    }
+}
+
+impl ArgDefault for HttpMethod {
+    fn arg_default() -> Option<Self> {
+        Some(HttpMethod(Ident::new("GET", Span::call_site())))
+    }
+}
+
+/// Options set on a params field with `#[param(...)]`.
+#[derive(Default)]
+struct ParamOptions {
+    rename: Option<String>,
+    repeated: bool,
+}
+
+impl ParamOptions {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = ParamOptions::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("param")) {
+            let nested = match attr.parse_meta()? {
+                Meta::List(list) => list.nested,
+                meta => return Err(syn::Error::new_spanned(meta, "Expected #[param(...)]")),
+            };
+            for meta in nested {
+                match meta {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(rename),
+                        ..
+                    })) if path.is_ident("rename") => options.rename = Some(rename.value()),
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("repeated") => {
+                        options.repeated = true
+                    }
+                    meta => return Err(syn::Error::new_spanned(meta, "Unknown param option")),
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
 #[proc_macro_attribute]
 pub fn generate_url_params(_: TokenStream, input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let struct_name = &input.ident;
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let struct_name = input.ident.clone();
 
-    let fields = match &input.data {
+    let fields = match &mut input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(fields),
             ..
-        }) => &fields.named,
+        }) => &mut fields.named,
         _ => panic!("Expected a struct with named fields"),
     };
 
-    let field_name = fields.iter().map(|field| &field.ident);
-
-    let mut string_name = vec![];
-
-    for field in fields.iter() {
-        string_name.push(field.clone().ident.unwrap().to_string())
+    let mut pair_tokens = vec![];
+
+    for field in fields.iter_mut() {
+        let options = match ParamOptions::from_attrs(&field.attrs) {
+            Ok(options) => options,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        field.attrs.retain(|attr| !attr.path.is_ident("param"));
+
+        let field_name = field.ident.clone().unwrap();
+        let key = options
+            .rename
+            .unwrap_or_else(|| field_name.unraw().to_string());
+
+        pair_tokens.push(if options.repeated {
+            quote! {
+                if let Some(values) = &self.#field_name {
+                    for value in values {
+                        pairs.push((#key, merge_config::query::QueryValue::to_query_value(value)));
+                    }
+                }
+            }
+        } else {
+            quote! {
+                if let Some(value) = &self.#field_name {
+                    pairs.push((#key, merge_config::query::QueryValue::to_query_value(value)));
+                }
+            }
+        });
     }
 
     TokenStream::from(quote! {
         #input
 
         impl #struct_name {
+            /// The set filters as unencoded `(key, value)` pairs, in field order.
+            pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+                let mut pairs = vec![];
+                #(#pair_tokens)*
+                pairs
+            }
+
             fn generate_url_params(&self) -> String {
-                let mut url = "".to_string();
-                let mut has_params = false;
-                #(
-                    if self.#field_name.is_some() {
-                        if has_params {
-                            url = format!("{}&{}={}", url, #string_name, self.clone().#field_name.unwrap());
-                        } else {
-                            url = format!("?{}={}", #string_name, self.clone().#field_name.unwrap());
-                            has_params = true;
-                        }
-                    }
-                )*
-                url
+                merge_config::query::encode_query(&self.query_pairs())
             }
         }
     })
@@ -115,6 +208,7 @@ derive_parse! {
         service: LitStr,
         model: LitStr,
         return_type: Type,
+        method: HttpMethod,
     }
 }
 
@@ -140,6 +234,16 @@ pub fn send_request(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let mut has_id = false;
 
+    let mut has_model = false;
+
+    let mut has_body = false;
+
+    let mut has_remote_user_id = false;
+
+    let mut has_file = false;
+
+    let mut has_range = false;
+
     for field in fields.iter() {
         let name = field.clone().ident.unwrap().to_string();
         if name == "config" {
@@ -148,75 +252,275 @@ pub fn send_request(args: TokenStream, input: TokenStream) -> TokenStream {
             has_params = true;
         } else if name == "id" {
             has_id = true;
+        } else if name == "model" {
+            has_model = true;
+        } else if name == "body" {
+            has_body = true;
+        } else if name == "remote_user_id" {
+            has_remote_user_id = true;
+        } else if name == "file" {
+            has_file = true;
+        } else if name == "range" {
+            has_range = true;
         }
     }
 
-    if !has_params || !has_config {
-        panic!("Expected a struct with fields named config and params");
+    if !has_config {
+        panic!("Expected a struct with a field named config");
     }
 
+    let method = &args.method.0;
+
+    let url_params_token = if has_params {
+        quote! {
+            match &self.params {
+                Some(params) => params.generate_url_params(),
+                None => "".to_string(),
+            }
+        }
+    } else {
+        quote! { "".to_string() }
+    };
+
+    // Merge expects writes wrapped as `{"model": {...}}`. A `body` field is sent as is. Uploads
+    // send the model as the `model` part of a multipart form next to the `file` part instead.
+    let body_token = if has_file {
+        let model_token = if has_model {
+            quote! {
+                form = form.text(
+                    "model",
+                    serde_json::to_string(&self.model)
+                        .map_err(merge_config::error::MergeError::Serialize)?,
+                );
+            }
+        } else {
+            quote! {}
+        };
+        quote! {
+            fn multipart(
+                &self,
+            ) -> Result<Option<merge_config::upload::Form>, merge_config::error::MergeError> {
+                let mut form = merge_config::upload::Form::new();
+                #model_token
+                Ok(Some(form.part("file", self.file.part())))
+            }
+        }
+    } else if has_model {
+        let remote_user_id_token = remote_user_id_body(has_remote_user_id);
+        quote! {
+            fn body(
+                &self,
+            ) -> Result<Option<serde_json::Value>, merge_config::error::MergeError> {
+                let mut body = serde_json::Map::new();
+                body.insert(
+                    "model".to_string(),
+                    serde_json::to_value(&self.model)
+                        .map_err(merge_config::error::MergeError::Serialize)?,
+                );
+                #remote_user_id_token
+                Ok(Some(serde_json::Value::Object(body)))
+            }
+        }
+    } else if has_body {
+        quote! {
+            fn body(
+                &self,
+            ) -> Result<Option<serde_json::Value>, merge_config::error::MergeError> {
+                serde_json::to_value(&self.body)
+                    .map(Some)
+                    .map_err(merge_config::error::MergeError::Serialize)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // An optional `range` field asks for part of a download only.
+    let headers_token = if has_range {
+        quote! {
+            fn headers(&self) -> merge_config::client::HeaderMap {
+                let mut headers = merge_config::client::HeaderMap::new();
+                if let Some(range) = &self.range {
+                    range.insert_into(&mut headers);
+                }
+                headers
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let return_type = args.return_type.to_token_stream();
 
-    let model = args.model.value();
+    // Downloads are streamed rather than decoded as JSON.
+    let send_token = if is_download(&args.return_type) {
+        quote! { download }
+    } else {
+        quote! { send }
+    };
+
+    let list_request_token = match paginated_item(&args.return_type) {
+        Some(item) => quote! {
+            impl merge_config::pagination::ListRequest<#item> for #struct_name {
+                fn set_cursor(&mut self, cursor: String) {
+                    self.params.get_or_insert_with(Default::default).cursor = Some(cursor);
+                }
+            }
+        },
+        None => quote! {},
+    };
+
+    let (model, path_fields) = match model_path(&args.model) {
+        Ok(model) => model,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-    let service = args.service.value();
+    // The service may be a placeholder too, for requests shared by every category.
+    let (service, _) = match model_path(&args.service) {
+        Ok(service) => service,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-    let url_token = if has_id {
+    // An `id` already placed in the model, e.g. `attachments/{id}/download`, is not appended.
+    let url_token = if has_id && !path_fields.iter().any(|field| field == "id") {
         quote! {
-            let url = format!("{}/api/{}/v1/{}/{}{}", url_base, #service, #model, &self.clone().id, url_params);
+            format!(
+                "/api/{}/v1/{}/{}{}",
+                #service,
+                #model,
+                merge_config::query::encode_path_segment(&self.id),
+                url_params
+            )
         }
     } else {
         quote! {
-            let url = format!("{}/api/{}/v1/{}{}", url_base, #service, #model, url_params);
+            format!("/api/{}/v1/{}{}", #service, #model, url_params)
         }
     };
 
     let tokens = quote! {
         #input
 
-        impl #struct_name {
-            pub async fn send_request(&self) -> Result<#return_type, String> {
-                #[cfg(test)]
-                use mockito;
+        impl merge_config::client::MergeRequest for #struct_name {
+            type Response = #return_type;
 
-                #[cfg(not(test))]
-                let url_base = "https://api.merge.dev";
+            fn method(&self) -> merge_config::client::Method {
+                merge_config::client::Method::#method
+            }
+
+            fn path(&self) -> String {
+                let url_params = #url_params_token;
 
-                #[cfg(test)]
-                let url_base = &mockito::server_url();
+                #url_token
+            }
 
-                let mut url_params = "".to_string();
+            #body_token
 
-                if self.clone().params.is_some() {
-                    url_params = self.clone().params.unwrap().generate_url_params();
-                }
+            #headers_token
 
-                #url_token
+            fn configuration(&self) -> Option<&merge_config::configuration::Configuration> {
+                self.config.as_ref()
+            }
+        }
 
-                let req_status = reqwest::Client::new()
-                    .get(&url)
-                    .bearer_auth(self.clone().config.api_key)
-                    .header("X-Account-Token", self.clone().config.access_token)
-                    .send()
-                    .await;
-
-                match req_status {
-                    Ok(req) => match req.status().is_success() {
-                        true => match serde_json::from_str::<#return_type>(req.text().await.unwrap().as_str()) {
-                            Ok(body) => Ok(body),
-                            Err(err) => Err(err.to_string())
-                        }
-                        false => Err(format!(
-                            "Request was not successfully status: {} body: {}",
-                            req.status().as_u16(),
-                            req.text().await.unwrap()
-                        )),
-                    },
-                    Err(err) => Err(err.to_string()),
+        impl #struct_name {
+            pub async fn send_request(
+                &self,
+            ) -> Result<#return_type, merge_config::error::MergeError> {
+                match &self.config {
+                    Some(config) => {
+                        merge_config::client::MergeClient::new(config.clone())
+                            .#send_token(self)
+                            .await
+                    }
+                    None => Err(merge_config::error::MergeError::MissingConfiguration),
                 }
             }
         }
+
+        #list_request_token
     };
 
     tokens.into()
 }
+
+/// ATS writes are made on behalf of a remote user, sent as `remote_user_id` next to the model.
+/// Requests without a `remote_user_id` field add nothing to the body.
+fn remote_user_id_body(has_remote_user_id: bool) -> proc_macro2::TokenStream {
+    if !has_remote_user_id {
+        return quote! {};
+    }
+    quote! {
+        body.insert(
+            "remote_user_id".to_string(),
+            serde_json::to_value(&self.remote_user_id)
+                .map_err(merge_config::error::MergeError::Serialize)?,
+        );
+    }
+}
+
+/// Turns the `model` or `service` argument into an expression for that part of the path.
+/// Segments written as `{field}` are filled in from the request field of that name, e.g.
+/// `model="jobs/{job_id}/screening-questions"`, each percent-encoded as a path segment.
+fn model_path(model: &LitStr) -> syn::Result<(proc_macro2::TokenStream, Vec<Ident>)> {
+    let value = model.value();
+    let mut format = String::new();
+    let mut fields = vec![];
+    let mut rest = value.as_str();
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| syn::Error::new(model.span(), "Unclosed `{` in path"))?;
+        format.push_str(&rest[..start]);
+        format.push_str("{}");
+        let field: Ident = syn::parse_str(&rest[start + 1..end])
+            .map_err(|_| syn::Error::new(model.span(), "Expected a field name between `{}`"))?;
+        fields.push(field);
+        rest = &rest[end + 1..];
+    }
+    format.push_str(rest);
+
+    if fields.is_empty() {
+        Ok((quote! { #value }, fields))
+    } else {
+        Ok((
+            quote! {
+                format!(
+                    #format,
+                    #(merge_config::query::encode_path_segment(&self.#fields)),*
+                )
+            },
+            fields,
+        ))
+    }
+}
+
+fn is_download(return_type: &Type) -> bool {
+    match return_type {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Download"),
+        _ => false,
+    }
+}
+
+/// Returns `T` when `return_type` is `Paginated<T>`.
+fn paginated_item(return_type: &Type) -> Option<&Type> {
+    let segment = match return_type {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Paginated" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(item) => Some(item),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,22 @@
+use merge_config::configuration::Configuration;
+pub use merge_config::configuration::Region;
+
+pub type TicketingConfig = Configuration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works_with_str() {
+        let config = TicketingConfig::new("someKey", "someToken");
+        assert_eq!(config.api_key, "someKey");
+        assert_eq!(config.access_token, "someToken")
+    }
+
+    #[test]
+    fn it_works_with_a_region() {
+        let config = TicketingConfig::new("someKey", "someToken").with_region(Region::Eu);
+        assert_eq!(config.base_url, "https://api-eu.merge.dev")
+    }
+}
@@ -0,0 +1,10 @@
+pub mod accounts;
+pub mod attachments;
+pub mod collections;
+pub mod comments;
+pub mod contacts;
+pub mod projects;
+pub mod tags;
+pub mod teams;
+pub mod tickets;
+pub mod users;
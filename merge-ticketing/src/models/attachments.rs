@@ -0,0 +1,351 @@
+use crate::configuration::TicketingConfig;
+use crate::models::tickets::TicketModel;
+use crate::models::users::UserModel;
+use chrono::{DateTime, Utc};
+use merge_config::download::Download;
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct AttachmentModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ticket: Option<Expandable<TicketModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub file_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub content_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub uploaded_by: Option<Expandable<UserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="attachments", return_type=Paginated<AttachmentModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `AttachmentModel` that `expand` can return in full.
+    pub enum AttachmentExpand {
+        Ticket => "ticket",
+        UploadedBy => "uploaded_by",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AttachmentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_id: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<AttachmentModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="attachments", return_type=AttachmentModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<AttachmentExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+/// Streams the content of an attachment, see `Download`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="attachments/{id}/download", return_type=Download)]
+pub struct DownloadRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<DownloadRequestParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct DownloadRequestParams {
+    /// Exports files from Google Drive in this format, e.g. `application/pdf`.
+    #[builder(setter(into, strip_option), default)]
+    pub mime_type: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::mock;
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/attachments?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"file_name\": \"Screenshot.png\",
+                      \"ticket\": \"cdfee2bc-43d6-4cae-9a3b-169ad31e966c\",
+                      \"file_url\": \"http://alturl.com/p749b\",
+                      \"content_type\": \"image/png\",
+                      \"uploaded_by\": \"4da35423-7898-45da-90bd-741b57ef40cb\",
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/attachments\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AttachmentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .file_name("Screenshot.png")
+            .ticket("cdfee2bc-43d6-4cae-9a3b-169ad31e966c")
+            .file_url("http://alturl.com/p749b")
+            .content_type("image/png")
+            .uploaded_by("4da35423-7898-45da-90bd-741b57ef40cb")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/attachments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/attachments/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"file_name\": \"Screenshot.png\",
+              \"ticket\": \"cdfee2bc-43d6-4cae-9a3b-169ad31e966c\",
+              \"file_url\": \"http://alturl.com/p749b\",
+              \"content_type\": \"image/png\",
+              \"uploaded_by\": \"4da35423-7898-45da-90bd-741b57ef40cb\",
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/attachments\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = AttachmentModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .file_name("Screenshot.png")
+            .ticket("cdfee2bc-43d6-4cae-9a3b-169ad31e966c")
+            .file_url("http://alturl.com/p749b")
+            .content_type("image/png")
+            .uploaded_by("4da35423-7898-45da-90bd-741b57ef40cb")
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/attachments")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: AttachmentModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_stream_a_download() {
+        let content = "a".repeat(64 * 1024);
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/attachments/19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c/download?mime_type=image%2Fpng",
+        )
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_header("content-disposition", "attachment; filename=\"Screenshot.png\"")
+        .with_body(&content)
+        .expect(1)
+        .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: DownloadRequestParams = DownloadRequestParamsBuilder::default()
+            .mime_type("image/png")
+            .build()
+            .unwrap();
+
+        let request = DownloadRequestBuilder::default()
+            .config(config)
+            .id("19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c")
+            .params(params)
+            .build()
+            .unwrap();
+
+        let download = request.send_request().await.unwrap();
+        assert_eq!(download.content_type(), Some("image/png"));
+        assert_eq!(download.file_name(), Some("Screenshot.png".to_string()));
+        assert_eq!(download.content_length(), Some(content.len() as u64));
+
+        let mut file = vec![];
+        let written = download.copy_to(&mut file).await.unwrap();
+        assert_eq!(written, content.len() as u64);
+        assert_eq!(file, content.as_bytes());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_a_missing_download() {
+        let m = mock("GET", "/api/ticketing/v1/attachments/missing/download")
+            .with_status(404)
+            .with_body("{\"detail\": \"Not found.\"}")
+            .expect(1)
+            .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = DownloadRequestBuilder::default()
+            .config(config)
+            .id("missing")
+            .build()
+            .unwrap();
+
+        let err = request.send_request().await.unwrap_err();
+        assert!(err.is_not_found());
+        m.assert()
+    }
+}
@@ -0,0 +1,777 @@
+use crate::configuration::TicketingConfig;
+use crate::models::accounts::AccountModel;
+use crate::models::attachments::AttachmentModel;
+use crate::models::collections::CollectionModel;
+use crate::models::contacts::ContactModel;
+use crate::models::users::UserModel;
+use chrono::{DateTime, Utc};
+use merge_config::expand::Expandable;
+use merge_config::expand_enum;
+use merge_config::pagination::Paginated;
+use merge_config::remote::RemoteData;
+use merge_config::write::{MetaResponse, WriteResponse};
+use serde::{Deserialize, Serialize};
+
+use merge_proc_macros::{generate_url_params, send_request};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TicketModel {
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub assignees: Option<Vec<Expandable<UserModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub creator: Option<Expandable<UserModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub due_date: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub collections: Option<Vec<Expandable<CollectionModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_type: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<Expandable<AccountModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<Expandable<ContactModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub parent_ticket: Option<Expandable<TicketModel>>,
+    #[builder(setter(into, strip_option), default)]
+    pub attachments: Option<Vec<Expandable<AttachmentModel>>>,
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<Vec<String>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub completed_at: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_url: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub priority: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_data: Option<Vec<RemoteData>>,
+    pub remote_was_deleted: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets", return_type=Paginated<TicketModel>)]
+pub struct GetRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestParams>,
+}
+
+expand_enum! {
+    /// The relations of `TicketModel` that `expand` can return in full.
+    pub enum TicketExpand {
+        Assignees => "assignees",
+        Creator => "creator",
+        Collections => "collections",
+        Account => "account",
+        Contact => "contact",
+        ParentTicket => "parent_ticket",
+        Attachments => "attachments",
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestParams {
+    #[builder(setter(into, strip_option), default)]
+    pub account_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub assignee_ids: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub collection_ids: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub completed_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub completed_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub contact_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub due_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub due_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TicketExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_deleted_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub modified_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub page_size: Option<i32>,
+    #[builder(setter(into, strip_option), default)]
+    pub parent_ticket_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub priority: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_created_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_id: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_updated_before: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<String>,
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_type: Option<String>,
+}
+
+pub type GetRequestResponse = Paginated<TicketModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets", return_type=TicketModel)]
+pub struct GetRequestById {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub id: String,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<GetRequestByIdParams>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct GetRequestByIdParams {
+    #[builder(setter(into, strip_option), default)]
+    pub expand: Option<Vec<TicketExpand>>,
+    #[builder(setter(into, strip_option), default)]
+    pub include_remote_data: Option<bool>,
+    #[builder(setter(into, strip_option), default)]
+    pub remote_fields: Option<String>,
+}
+
+/// The fields accepted when creating or updating a ticket. Which of them a linked account's
+/// integration actually needs can be discovered with `MetaPostRequest` and `MetaPatchRequest`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[builder(setter(into))]
+pub struct TicketRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub collections: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub contact: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parent_ticket: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub attachments: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub ticket_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub integration_params: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub linked_account_params: Option<serde_json::Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets", return_type=WriteResponse<TicketModel>, method="POST")]
+pub struct CreateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub model: TicketRequest,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<CreateRequestParams>,
+}
+
+pub type CreateRequestResponse = WriteResponse<TicketModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets", return_type=WriteResponse<TicketModel>, method="PATCH")]
+pub struct UpdateRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub id: String,
+    pub model: TicketRequest,
+    #[builder(setter(into, strip_option), default)]
+    pub params: Option<UpdateRequestParams>,
+}
+
+pub type UpdateRequestResponse = WriteResponse<TicketModel>;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct CreateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[generate_url_params]
+pub struct UpdateRequestParams {
+    /// Returns the calls made to the third party in `WriteResponse::logs`.
+    #[builder(setter(into, strip_option), default)]
+    pub is_debug_mode: Option<bool>,
+    /// Queues the write instead of waiting for the third party to answer.
+    #[builder(setter(into, strip_option), default)]
+    pub run_async: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets/meta/post", return_type=MetaResponse)]
+pub struct MetaPostRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(into))]
+#[send_request(service="ticketing", model="tickets/meta/patch", return_type=MetaResponse)]
+pub struct MetaPatchRequest {
+    #[builder(setter(into, strip_option), default)]
+    pub config: Option<TicketingConfig>,
+    pub id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merge_config::remote::RemoteDataBuilder;
+    use mockito::{mock, Matcher};
+
+    #[tokio::test]
+    async fn it_should_make_request() {
+        let m = mock("GET", "/api/ticketing/v1/tickets?include_remote_data=true")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"next\": \"cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw\",
+                  \"previous\": \"cj1sZXdwd2VycWVtY29zZnNkc2NzUWxNMEUxTXk0ME16UXpNallsTWtJ\",
+                  \"results\": [
+                    {
+                      \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+                      \"remote_id\": \"19202938\",
+                      \"name\": \"Please add more integrations\",
+                      \"assignees\": [
+                        \"d2f972d0-2526-434b-9409-4c3b468e08f0\"
+                      ],
+                      \"creator\": \"ee243325-9b0f-4399-940e-81d2c98a38b6\",
+                      \"due_date\": \"2021-10-01\",
+                      \"status\": \"OPEN\",
+                      \"description\": \"Can you please add more integrations? It'll make syncing data much easier!\",
+                      \"collections\": [
+                        \"563b0ae3-9d6b-4b28-9ab7-e12c6a3fe4a5\"
+                      ],
+                      \"ticket_type\": \"incident\",
+                      \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+                      \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+                      \"parent_ticket\": \"68eb718b-feb3-431e-9550-52e41db81fe6\",
+                      \"attachments\": [
+                        \"19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c\"
+                      ],
+                      \"tags\": [
+                        \"enterprise\"
+                      ],
+                      \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+                      \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+                      \"completed_at\": \"2021-12-06T10:11:26Z\",
+                      \"ticket_url\": \"https://thirdpartytool.com/ticket/1\",
+                      \"priority\": \"HIGH\",
+                      \"remote_data\": [
+                        {
+                          \"path\": \"/tickets\",
+                          \"data\": [
+                            \"Varies by platform\"
+                          ]
+                        }
+                      ],
+                      \"remote_was_deleted\": false
+                    }
+                  ]
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let expected_model = TicketModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Please add more integrations")
+            .assignees(vec![Expandable::from(
+                "d2f972d0-2526-434b-9409-4c3b468e08f0",
+            )])
+            .creator("ee243325-9b0f-4399-940e-81d2c98a38b6")
+            .due_date("2021-10-01")
+            .status("OPEN")
+            .description(
+                "Can you please add more integrations? It'll make syncing data much easier!",
+            )
+            .collections(vec![Expandable::from(
+                "563b0ae3-9d6b-4b28-9ab7-e12c6a3fe4a5",
+            )])
+            .ticket_type("incident")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .parent_ticket("68eb718b-feb3-431e-9550-52e41db81fe6")
+            .attachments(vec![Expandable::from(
+                "19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c",
+            )])
+            .tags(vec!["enterprise".to_string()])
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .completed_at("2021-12-06T10:11:26Z")
+            .ticket_url("https://thirdpartytool.com/ticket/1")
+            .priority("HIGH")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/tickets")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.next,
+            Some("cD0yMDIxLTAxLTA2KzAzJTNBMjQlM0E1My40MzQzMjYlMkIwMCUzQTAw".to_string())
+        );
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results.first().unwrap().clone(), expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_make_request_by_id() {
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/tickets/b80bb774-0288-4da1-9201-890375a60c8f?include_remote_data=true",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+            {
+              \"id\": \"b80bb774-0288-4da1-9201-890375a60c8f\",
+              \"remote_id\": \"19202938\",
+              \"name\": \"Please add more integrations\",
+              \"assignees\": [
+                \"d2f972d0-2526-434b-9409-4c3b468e08f0\"
+              ],
+              \"creator\": \"ee243325-9b0f-4399-940e-81d2c98a38b6\",
+              \"due_date\": \"2021-10-01\",
+              \"status\": \"OPEN\",
+              \"description\": \"Can you please add more integrations? It'll make syncing data much easier!\",
+              \"collections\": [
+                \"563b0ae3-9d6b-4b28-9ab7-e12c6a3fe4a5\"
+              ],
+              \"ticket_type\": \"incident\",
+              \"account\": \"e268443e-43d9-4dab-9ebe-f303bbe9642f\",
+              \"contact\": \"2f8a6bf3-1f3b-467b-92d9-720c58b19c9a\",
+              \"parent_ticket\": \"68eb718b-feb3-431e-9550-52e41db81fe6\",
+              \"attachments\": [
+                \"19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c\"
+              ],
+              \"tags\": [
+                \"enterprise\"
+              ],
+              \"remote_created_at\": \"2021-12-06T10:11:26Z\",
+              \"remote_updated_at\": \"2021-12-06T10:11:26Z\",
+              \"completed_at\": \"2021-12-06T10:11:26Z\",
+              \"ticket_url\": \"https://thirdpartytool.com/ticket/1\",
+              \"priority\": \"HIGH\",
+              \"remote_data\": [
+                {
+                  \"path\": \"/tickets\",
+                  \"data\": [
+                    \"Varies by platform\"
+                  ]
+                }
+              ],
+              \"remote_was_deleted\": false
+            }",
+        )
+        .expect(1)
+        .create();
+
+        let expected_model = TicketModelBuilder::default()
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .remote_id("19202938")
+            .name("Please add more integrations")
+            .assignees(vec![Expandable::from(
+                "d2f972d0-2526-434b-9409-4c3b468e08f0",
+            )])
+            .creator("ee243325-9b0f-4399-940e-81d2c98a38b6")
+            .due_date("2021-10-01")
+            .status("OPEN")
+            .description(
+                "Can you please add more integrations? It'll make syncing data much easier!",
+            )
+            .collections(vec![Expandable::from(
+                "563b0ae3-9d6b-4b28-9ab7-e12c6a3fe4a5",
+            )])
+            .ticket_type("incident")
+            .account("e268443e-43d9-4dab-9ebe-f303bbe9642f")
+            .contact("2f8a6bf3-1f3b-467b-92d9-720c58b19c9a")
+            .parent_ticket("68eb718b-feb3-431e-9550-52e41db81fe6")
+            .attachments(vec![Expandable::from(
+                "19b0e6a8-5db1-4a3d-9a3a-a9c0f2e8ea1c",
+            )])
+            .tags(vec!["enterprise".to_string()])
+            .remote_created_at("2021-12-06T10:11:26Z")
+            .remote_updated_at("2021-12-06T10:11:26Z")
+            .completed_at("2021-12-06T10:11:26Z")
+            .ticket_url("https://thirdpartytool.com/ticket/1")
+            .priority("HIGH")
+            .remote_data(vec![RemoteDataBuilder::default()
+                .path("/tickets")
+                .data(vec!["Varies by platform".to_string()])
+                .build()
+                .unwrap()])
+            .remote_was_deleted(false)
+            .build()
+            .unwrap();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestByIdParams = GetRequestByIdParamsBuilder::default()
+            .include_remote_data(true)
+            .build()
+            .unwrap();
+
+        let request = GetRequestByIdBuilder::default()
+            .config(config)
+            .params(params)
+            .id("b80bb774-0288-4da1-9201-890375a60c8f")
+            .build()
+            .unwrap();
+
+        let response: TicketModel = request.send_request().await.unwrap();
+
+        assert_eq!(response, expected_model);
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_tickets() {
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/tickets?assignee_ids=d2f972d0&priority=HIGH&status=OPEN",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"next\": null, \"previous\": null, \"results\": []}")
+        .expect(1)
+        .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let params: GetRequestParams = GetRequestParamsBuilder::default()
+            .assignee_ids("d2f972d0")
+            .priority("HIGH")
+            .status("OPEN")
+            .build()
+            .unwrap();
+
+        let request = GetRequestBuilder::default()
+            .config(config)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response: GetRequestResponse = request.send_request().await.unwrap();
+
+        assert!(response.results.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_create_a_ticket() {
+        let m = mock("POST", "/api/ticketing/v1/tickets?is_debug_mode=true")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "model": {
+                    "name": "Please add more integrations",
+                    "status": "OPEN",
+                    "priority": "HIGH",
+                    "assignees": [
+                        "d2f972d0-2526-434b-9409-4c3b468e08f0"
+                    ]
+                }
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"model\": {
+                    \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                    \"name\": \"Please add more integrations\",
+                    \"status\": \"OPEN\",
+                    \"priority\": \"HIGH\",
+                    \"assignees\": [
+                      \"d2f972d0-2526-434b-9409-4c3b468e08f0\"
+                    ],
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": [],
+                  \"logs\": []
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let ticket: TicketRequest = TicketRequestBuilder::default()
+            .name("Please add more integrations")
+            .status("OPEN")
+            .priority("HIGH")
+            .assignees(vec!["d2f972d0-2526-434b-9409-4c3b468e08f0".to_string()])
+            .build()
+            .unwrap();
+
+        let params: CreateRequestParams = CreateRequestParamsBuilder::default()
+            .is_debug_mode(true)
+            .build()
+            .unwrap();
+
+        let request = CreateRequestBuilder::default()
+            .config(config)
+            .model(ticket)
+            .params(params)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.id, "0958cbc6-6040-430a-848e-aafacbadf4ae");
+        assert_eq!(response.model.priority, Some("HIGH".to_string()));
+        assert!(response.errors.is_empty());
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_update_a_ticket() {
+        let m = mock(
+            "PATCH",
+            "/api/ticketing/v1/tickets/0958cbc6-6040-430a-848e-aafacbadf4ae",
+        )
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": {
+                "status": "CLOSED",
+                "completed_at": "2022-10-11T00:00:00Z"
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"model\": {
+                    \"id\": \"0958cbc6-6040-430a-848e-aafacbadf4ae\",
+                    \"status\": \"CLOSED\",
+                    \"completed_at\": \"2022-10-11T00:00:00Z\",
+                    \"remote_was_deleted\": false
+                  },
+                  \"warnings\": [],
+                  \"errors\": []
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let ticket: TicketRequest = TicketRequestBuilder::default()
+            .status("CLOSED")
+            .completed_at("2022-10-11T00:00:00Z")
+            .build()
+            .unwrap();
+
+        let request = UpdateRequestBuilder::default()
+            .config(config)
+            .id("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .model(ticket)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(response.model.status, Some("CLOSED".to_string()));
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_post_schema() {
+        let m = mock("GET", "/api/ticketing/v1/tickets/meta/post")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"has_conditional_params\": false,
+                  \"has_required_linked_account_params\": false
+                }",
+            )
+            .expect(1)
+            .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPostRequestBuilder::default()
+            .config(config)
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "name"
+        );
+        m.assert()
+    }
+
+    #[tokio::test]
+    async fn it_should_discover_the_patch_schema() {
+        let m = mock(
+            "GET",
+            "/api/ticketing/v1/tickets/meta/patch/0958cbc6-6040-430a-848e-aafacbadf4ae",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            "
+                {
+                  \"request_schema\": {
+                    \"type\": \"object\",
+                    \"properties\": {
+                      \"model\": {
+                        \"type\": \"object\",
+                        \"required\": [
+                          \"name\"
+                        ]
+                      }
+                    }
+                  },
+                  \"has_conditional_params\": false,
+                  \"has_required_linked_account_params\": false
+                }",
+        )
+        .expect(1)
+        .create();
+
+        let config = TicketingConfig::new("test", "test").with_base_url(mockito::server_url());
+
+        let request = MetaPatchRequestBuilder::default()
+            .config(config)
+            .id("0958cbc6-6040-430a-848e-aafacbadf4ae")
+            .build()
+            .unwrap();
+
+        let response = request.send_request().await.unwrap();
+
+        assert_eq!(
+            response.request_schema["properties"]["model"]["required"][0],
+            "name"
+        );
+        m.assert()
+    }
+}
@@ -0,0 +1,30 @@
+pub use merge_accounting::account::{self, Category};
+pub use merge_accounting::client::MergeClient;
+pub use merge_accounting::configuration::{AccountingConfig, Region};
+pub use merge_accounting::error::MergeError;
+pub use merge_accounting::expand::Expandable;
+pub use merge_accounting::link;
+pub use merge_accounting::models::accounts;
+pub use merge_accounting::models::addresses;
+pub use merge_accounting::models::balance_sheets;
+pub use merge_accounting::models::cash_flow_statements;
+pub use merge_accounting::models::company_info;
+pub use merge_accounting::models::contacts;
+pub use merge_accounting::models::credit_notes;
+pub use merge_accounting::models::expenses;
+pub use merge_accounting::models::income_statements;
+pub use merge_accounting::models::invoices;
+pub use merge_accounting::models::items;
+pub use merge_accounting::models::journal_entries;
+pub use merge_accounting::models::payments;
+pub use merge_accounting::models::purchase_orders;
+pub use merge_accounting::models::tax_rates;
+pub use merge_accounting::models::tracking_categories;
+pub use merge_accounting::models::transactions;
+pub use merge_accounting::models::vendor_credits;
+pub use merge_accounting::pagination::Paginated;
+pub use merge_accounting::passthrough;
+pub use merge_accounting::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_accounting::report::{Report, ReportItem, ReportRow, RollupMismatch};
+pub use merge_accounting::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_accounting::Decimal;
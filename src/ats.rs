@@ -0,0 +1,27 @@
+pub use merge_ats::account::{self, Category};
+pub use merge_ats::client::MergeClient;
+pub use merge_ats::configuration::{ATSConfig, Region};
+pub use merge_ats::error::MergeError;
+pub use merge_ats::expand::Expandable;
+pub use merge_ats::link;
+pub use merge_ats::models::activities;
+pub use merge_ats::models::applications;
+pub use merge_ats::models::attachments;
+pub use merge_ats::models::candidates;
+pub use merge_ats::models::departments;
+pub use merge_ats::models::eeocs;
+pub use merge_ats::models::interviews;
+pub use merge_ats::models::job_interview_stages;
+pub use merge_ats::models::jobs;
+pub use merge_ats::models::offers;
+pub use merge_ats::models::offices;
+pub use merge_ats::models::reject_reasons;
+pub use merge_ats::models::remote_users;
+pub use merge_ats::models::scorecards;
+pub use merge_ats::models::screening_questions;
+pub use merge_ats::models::tags;
+pub use merge_ats::pagination::Paginated;
+pub use merge_ats::passthrough;
+pub use merge_ats::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_ats::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_ats::write::{MetaResponse, WriteResponse};
@@ -0,0 +1,25 @@
+pub use merge_crm::account::{self, Category};
+pub use merge_crm::client::MergeClient;
+pub use merge_crm::configuration::{CRMConfig, Region};
+pub use merge_crm::error::MergeError;
+pub use merge_crm::expand::Expandable;
+pub use merge_crm::link;
+pub use merge_crm::models::accounts;
+pub use merge_crm::models::association_types;
+pub use merge_crm::models::associations;
+pub use merge_crm::models::contacts;
+pub use merge_crm::models::custom_object_classes;
+pub use merge_crm::models::custom_objects;
+pub use merge_crm::models::engagement_types;
+pub use merge_crm::models::engagements;
+pub use merge_crm::models::leads;
+pub use merge_crm::models::notes;
+pub use merge_crm::models::opportunities;
+pub use merge_crm::models::stages;
+pub use merge_crm::models::tasks;
+pub use merge_crm::models::users;
+pub use merge_crm::pagination::Paginated;
+pub use merge_crm::passthrough;
+pub use merge_crm::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_crm::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_crm::write::{MetaResponse, WriteResponse};
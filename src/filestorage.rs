@@ -0,0 +1,18 @@
+pub use merge_filestorage::account::{self, Category};
+pub use merge_filestorage::client::MergeClient;
+pub use merge_filestorage::configuration::{FileStorageConfig, Region};
+pub use merge_filestorage::download::{ByteRange, ContentRange, Download};
+pub use merge_filestorage::error::MergeError;
+pub use merge_filestorage::expand::Expandable;
+pub use merge_filestorage::link;
+pub use merge_filestorage::models::drives;
+pub use merge_filestorage::models::files;
+pub use merge_filestorage::models::folders;
+pub use merge_filestorage::models::groups;
+pub use merge_filestorage::models::users;
+pub use merge_filestorage::pagination::Paginated;
+pub use merge_filestorage::passthrough;
+pub use merge_filestorage::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_filestorage::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_filestorage::upload::FileUpload;
+pub use merge_filestorage::write::{MetaResponse, WriteResponse};
@@ -1,3 +1,28 @@
-pub use merge_hris::configuration::HRISConfig;
+pub use merge_hris::account::{self, Category};
+pub use merge_hris::client::MergeClient;
+pub use merge_hris::configuration::{HRISConfig, Region};
+pub use merge_hris::error::MergeError;
+pub use merge_hris::expand::Expandable;
+pub use merge_hris::link;
 pub use merge_hris::models::bank_info;
 pub use merge_hris::models::benefits;
+pub use merge_hris::models::companies;
+pub use merge_hris::models::deductions;
+pub use merge_hris::models::dependents;
+pub use merge_hris::models::employee_payroll_runs;
+pub use merge_hris::models::employees;
+pub use merge_hris::models::employer_benefits;
+pub use merge_hris::models::employments;
+pub use merge_hris::models::groups;
+pub use merge_hris::models::locations;
+pub use merge_hris::models::pay_groups;
+pub use merge_hris::models::payroll_runs;
+pub use merge_hris::models::teams;
+pub use merge_hris::models::time_off;
+pub use merge_hris::models::time_off_balances;
+pub use merge_hris::models::timesheet_entries;
+pub use merge_hris::pagination::Paginated;
+pub use merge_hris::passthrough;
+pub use merge_hris::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_hris::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_hris::write::{MetaResponse, WriteResponse};
@@ -1 +1,7 @@
+pub mod accounting;
+pub mod ats;
+pub mod crm;
+pub mod filestorage;
 pub mod hris;
+pub mod ticketing;
+pub mod webhooks;
@@ -0,0 +1,22 @@
+pub use merge_ticketing::account::{self, Category};
+pub use merge_ticketing::client::MergeClient;
+pub use merge_ticketing::configuration::{Region, TicketingConfig};
+pub use merge_ticketing::download::Download;
+pub use merge_ticketing::error::MergeError;
+pub use merge_ticketing::expand::Expandable;
+pub use merge_ticketing::link;
+pub use merge_ticketing::models::accounts;
+pub use merge_ticketing::models::attachments;
+pub use merge_ticketing::models::collections;
+pub use merge_ticketing::models::comments;
+pub use merge_ticketing::models::contacts;
+pub use merge_ticketing::models::projects;
+pub use merge_ticketing::models::tags;
+pub use merge_ticketing::models::teams;
+pub use merge_ticketing::models::tickets;
+pub use merge_ticketing::models::users;
+pub use merge_ticketing::pagination::Paginated;
+pub use merge_ticketing::passthrough;
+pub use merge_ticketing::remote::{RemoteData, RemoteField, RemoteFieldClass};
+pub use merge_ticketing::retry::{RateLimit, RetryPolicy, RetryPolicyBuilder};
+pub use merge_ticketing::write::{MetaResponse, WriteResponse};
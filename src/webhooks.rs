@@ -0,0 +1,626 @@
+//! Receiving Merge webhooks.
+//!
+//! Every webhook is signed with the signature key from the Merge dashboard. Check it with a
+//! `WebhookVerifier` before trusting the payload:
+//!
+//! ```no_run
+//! # fn example(body: &[u8], signature: Option<&str>) -> Result<(), merge_dev::webhooks::WebhookError> {
+//! use merge_dev::webhooks::{WebhookEvent, WebhookVerifier};
+//!
+//! let verifier = WebhookVerifier::new("signature key");
+//! let payload = verifier.parse(body, signature)?;
+//! if let WebhookEvent::SyncCompleted(sync) = payload.event {
+//!     println!("{:?} finished syncing", sync.linked_account_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::hris::account::{AccountDetailsModel, Category, SyncStatusModel};
+use crate::{accounting, ats, crm, filestorage, hris, ticketing};
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+/// The header carrying the signature of the raw body.
+pub const SIGNATURE_HEADER: &str = "X-Merge-Webhook-Signature";
+
+/// Signatures are decoded with or without their trailing `=` padding.
+const SIGNATURE_CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, SIGNATURE_CONFIG);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, SIGNATURE_CONFIG);
+
+#[derive(Debug)]
+pub enum WebhookError {
+    MissingSignature,
+    /// The signature does not match the body, so the request did not come from Merge or was
+    /// altered on the way.
+    InvalidSignature,
+    /// The body could not be read.
+    Body(Box<dyn Error + Send + Sync>),
+    /// The body is signed correctly but is not a webhook payload at all, e.g. not JSON or without
+    /// a `hook`. Data that only fails to decode into its model is kept as `WebhookEvent::Other`.
+    Payload(serde_json::Error),
+}
+
+impl WebhookError {
+    /// Whether the request should be rejected as unauthenticated rather than malformed.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self,
+            WebhookError::MissingSignature | WebhookError::InvalidSignature
+        )
+    }
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::MissingSignature => write!(f, "missing {} header", SIGNATURE_HEADER),
+            WebhookError::InvalidSignature => write!(f, "invalid webhook signature"),
+            WebhookError::Body(err) => write!(f, "failed to read the webhook body: {}", err),
+            WebhookError::Payload(err) => write!(f, "invalid webhook payload: {}", err),
+        }
+    }
+}
+
+impl Error for WebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WebhookError::Body(err) => Some(err.as_ref()),
+            WebhookError::Payload(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Checks webhook signatures against the signature key from the Merge dashboard.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    key: Vec<u8>,
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookVerifier").finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    pub fn new<K>(key: K) -> Self
+    where
+        K: AsRef<[u8]>,
+    {
+        WebhookVerifier {
+            key: key.as_ref().to_vec(),
+        }
+    }
+
+    /// Checks that `signature`, the base64 encoded HMAC-SHA256 of the raw body, matches `body`.
+    /// The comparison takes the same time wherever the first differing byte is.
+    pub fn verify(&self, body: &[u8], signature: Option<&str>) -> Result<(), WebhookError> {
+        let signature = signature.ok_or(WebhookError::MissingSignature)?.trim();
+        let signature = URL_SAFE
+            .decode(signature)
+            .or_else(|_| STANDARD.decode(signature))
+            .map_err(|_| WebhookError::InvalidSignature)?;
+
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac.verify_slice(&signature)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+
+    /// Verifies the signature and only then decodes the payload.
+    pub fn parse(
+        &self,
+        body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<WebhookPayload, WebhookError> {
+        self.verify(body, signature)?;
+        WebhookPayload::from_slice(body)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hook {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// e.g. `Employee.changed` or `LinkedAccount.sync_completed`.
+    pub event: String,
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookPayload {
+    pub hook: Hook,
+    pub linked_account: Option<AccountDetailsModel>,
+    pub event: WebhookEvent,
+}
+
+#[derive(Deserialize)]
+struct RawPayload {
+    hook: Hook,
+    #[serde(default)]
+    linked_account: Option<AccountDetailsModel>,
+    #[serde(default)]
+    data: Value,
+}
+
+impl WebhookPayload {
+    /// Decodes a payload without checking its signature, see `WebhookVerifier::parse`.
+    pub fn from_slice(body: &[u8]) -> Result<Self, WebhookError> {
+        let raw: RawPayload = serde_json::from_slice(body).map_err(WebhookError::Payload)?;
+        let category = raw
+            .linked_account
+            .as_ref()
+            .and_then(|account| account.category.as_deref())
+            .and_then(category);
+        let event = WebhookEvent::parse(&raw.hook.event, category, raw.data);
+        Ok(WebhookPayload {
+            hook: raw.hook,
+            linked_account: raw.linked_account,
+            event,
+        })
+    }
+}
+
+fn category(name: &str) -> Option<Category> {
+    serde_json::from_value(Value::from(name)).ok()
+}
+
+/// Sent with `LinkedAccount.sync_completed`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCompletedData {
+    #[serde(default)]
+    pub integration_name: Option<String>,
+    #[serde(default)]
+    pub integration_id: Option<String>,
+    #[serde(default)]
+    pub linked_account_id: Option<String>,
+    #[serde(default)]
+    pub sync_status: Option<SyncStatusModel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeAction {
+    Added,
+    Changed,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    /// `LinkedAccount.linked`
+    AccountLinked,
+    /// `LinkedAccount.sync_completed`
+    SyncCompleted(SyncCompletedData),
+    /// `LinkedAccount.account_deleted`
+    AccountDeleted,
+    /// `<Model>.added`, `<Model>.changed` or `<Model>.removed`, with the model as it is now.
+    Model {
+        action: ChangeAction,
+        model: CommonModel,
+    },
+    /// An event this crate has no type for, or whose data does not decode into it, kept as sent
+    /// so a verified webhook is never lost to a model that drifted from the API.
+    Other { event: String, data: Value },
+}
+
+impl WebhookEvent {
+    /// The category comes from the event itself, e.g. `hris.Employee.changed`, or else from the
+    /// linked account the webhook was sent for.
+    fn parse(event: &str, category: Option<Category>, data: Value) -> Self {
+        let (subject, action) = event.rsplit_once('.').unwrap_or((event, ""));
+        match (subject, action) {
+            ("LinkedAccount", "linked") => return WebhookEvent::AccountLinked,
+            ("LinkedAccount", "sync_completed") => {
+                return match SyncCompletedData::deserialize(&data) {
+                    Ok(sync) => WebhookEvent::SyncCompleted(sync),
+                    Err(_) => WebhookEvent::other(event, data),
+                }
+            }
+            ("LinkedAccount", "account_deleted") => return WebhookEvent::AccountDeleted,
+            _ => {}
+        }
+
+        let action = match action {
+            "added" => Some(ChangeAction::Added),
+            "changed" => Some(ChangeAction::Changed),
+            "removed" => Some(ChangeAction::Removed),
+            _ => None,
+        };
+        let (category, name) = match subject.split_once('.') {
+            Some((prefix, name)) => (self::category(prefix), name),
+            None => (category, subject),
+        };
+        match (action, category) {
+            (Some(action), Some(category)) => match CommonModel::parse(category, name, &data) {
+                Ok(Some(model)) => WebhookEvent::Model { action, model },
+                Ok(None) | Err(_) => WebhookEvent::other(event, data),
+            },
+            _ => WebhookEvent::other(event, data),
+        }
+    }
+
+    fn other(event: &str, data: Value) -> Self {
+        WebhookEvent::Other {
+            event: event.to_string(),
+            data,
+        }
+    }
+}
+
+/// Declares the models of one category that can be sent with a change event, named as Merge
+/// names them in `hook.event`.
+macro_rules! category_models {
+    ($name:ident { $($variant:ident => $model:ty,)* }) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum $name {
+            $($variant(Box<$model>),)*
+        }
+
+        impl $name {
+            fn parse(name: &str, data: &Value) -> serde_json::Result<Option<Self>> {
+                match name {
+                    $(stringify!($variant) => Deserialize::deserialize(data).map(|model| Some($name::$variant(model))),)*
+                    _ => Ok(None),
+                }
+            }
+        }
+    };
+}
+
+category_models!(HrisModel {
+    BankInfo => hris::bank_info::BankInfoModel,
+    Benefit => hris::benefits::BenefitModel,
+    Company => hris::companies::CompanyModel,
+    Deduction => hris::deductions::DeductionModel,
+    Dependent => hris::dependents::DependentModel,
+    Employee => hris::employees::EmployeeModel,
+    EmployeePayrollRun => hris::employee_payroll_runs::EmployeePayrollRunModel,
+    EmployerBenefit => hris::employer_benefits::EmployerBenefitModel,
+    Employment => hris::employments::EmploymentModel,
+    Group => hris::groups::GroupModel,
+    Location => hris::locations::LocationModel,
+    PayGroup => hris::pay_groups::PayGroupModel,
+    PayrollRun => hris::payroll_runs::PayrollRunModel,
+    Team => hris::teams::TeamModel,
+    TimeOff => hris::time_off::TimeOffModel,
+    TimeOffBalance => hris::time_off_balances::TimeOffBalanceModel,
+    TimesheetEntry => hris::timesheet_entries::TimesheetEntryModel,
+});
+
+category_models!(AtsModel {
+    Activity => ats::activities::ActivityModel,
+    Application => ats::applications::ApplicationModel,
+    Attachment => ats::attachments::AttachmentModel,
+    Candidate => ats::candidates::CandidateModel,
+    Department => ats::departments::DepartmentModel,
+    EEOC => ats::eeocs::EEOCModel,
+    Job => ats::jobs::JobModel,
+    JobInterviewStage => ats::job_interview_stages::JobInterviewStageModel,
+    Offer => ats::offers::OfferModel,
+    Office => ats::offices::OfficeModel,
+    RejectReason => ats::reject_reasons::RejectReasonModel,
+    RemoteUser => ats::remote_users::RemoteUserModel,
+    ScheduledInterview => ats::interviews::ScheduledInterviewModel,
+    Scorecard => ats::scorecards::ScorecardModel,
+    ScreeningQuestion => ats::screening_questions::ScreeningQuestionModel,
+    Tag => ats::tags::TagModel,
+});
+
+category_models!(AccountingModel {
+    Account => accounting::accounts::AccountModel,
+    Address => accounting::addresses::AddressModel,
+    BalanceSheet => accounting::balance_sheets::BalanceSheetModel,
+    CashFlowStatement => accounting::cash_flow_statements::CashFlowStatementModel,
+    CompanyInfo => accounting::company_info::CompanyInfoModel,
+    Contact => accounting::contacts::ContactModel,
+    CreditNote => accounting::credit_notes::CreditNoteModel,
+    Expense => accounting::expenses::ExpenseModel,
+    IncomeStatement => accounting::income_statements::IncomeStatementModel,
+    Invoice => accounting::invoices::InvoiceModel,
+    Item => accounting::items::ItemModel,
+    JournalEntry => accounting::journal_entries::JournalEntryModel,
+    Payment => accounting::payments::PaymentModel,
+    PurchaseOrder => accounting::purchase_orders::PurchaseOrderModel,
+    TaxRate => accounting::tax_rates::TaxRateModel,
+    TrackingCategory => accounting::tracking_categories::TrackingCategoryModel,
+    Transaction => accounting::transactions::TransactionModel,
+    VendorCredit => accounting::vendor_credits::VendorCreditModel,
+});
+
+category_models!(CrmModel {
+    Account => crm::accounts::AccountModel,
+    Association => crm::associations::AssociationModel,
+    AssociationType => crm::association_types::AssociationTypeModel,
+    Contact => crm::contacts::ContactModel,
+    CustomObject => crm::custom_objects::CustomObjectModel,
+    CustomObjectClass => crm::custom_object_classes::CustomObjectClassModel,
+    Engagement => crm::engagements::EngagementModel,
+    EngagementType => crm::engagement_types::EngagementTypeModel,
+    Lead => crm::leads::LeadModel,
+    Note => crm::notes::NoteModel,
+    Opportunity => crm::opportunities::OpportunityModel,
+    Stage => crm::stages::StageModel,
+    Task => crm::tasks::TaskModel,
+    User => crm::users::UserModel,
+});
+
+category_models!(TicketingModel {
+    Account => ticketing::accounts::AccountModel,
+    Attachment => ticketing::attachments::AttachmentModel,
+    Collection => ticketing::collections::CollectionModel,
+    Comment => ticketing::comments::CommentModel,
+    Contact => ticketing::contacts::ContactModel,
+    Project => ticketing::projects::ProjectModel,
+    Tag => ticketing::tags::TagModel,
+    Team => ticketing::teams::TeamModel,
+    Ticket => ticketing::tickets::TicketModel,
+    User => ticketing::users::UserModel,
+});
+
+category_models!(FileStorageModel {
+    Drive => filestorage::drives::DriveModel,
+    File => filestorage::files::FileModel,
+    Folder => filestorage::folders::FolderModel,
+    Group => filestorage::groups::GroupModel,
+    User => filestorage::users::UserModel,
+});
+
+/// The model sent with a change event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommonModel {
+    Hris(HrisModel),
+    Ats(AtsModel),
+    Accounting(AccountingModel),
+    Crm(CrmModel),
+    Ticketing(TicketingModel),
+    FileStorage(FileStorageModel),
+}
+
+impl CommonModel {
+    fn parse(category: Category, name: &str, data: &Value) -> serde_json::Result<Option<Self>> {
+        Ok(match category {
+            Category::Hris => HrisModel::parse(name, data)?.map(CommonModel::Hris),
+            Category::Ats => AtsModel::parse(name, data)?.map(CommonModel::Ats),
+            Category::Accounting => {
+                AccountingModel::parse(name, data)?.map(CommonModel::Accounting)
+            }
+            Category::Crm => CrmModel::parse(name, data)?.map(CommonModel::Crm),
+            Category::Ticketing => TicketingModel::parse(name, data)?.map(CommonModel::Ticketing),
+            Category::FileStorage => {
+                FileStorageModel::parse(name, data)?.map(CommonModel::FileStorage)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "someSignatureKey";
+
+    const LINKED: &str = r#"{"hook":{"event":"LinkedAccount.linked"},"data":{}}"#;
+
+    fn sign(body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(KEY.as_bytes()).unwrap();
+        mac.update(body.as_bytes());
+        URL_SAFE.encode(mac.finalize().into_bytes())
+    }
+
+    fn changed(category: &str, event: &str, data: &str) -> String {
+        format!(
+            r#"{{
+                "hook": {{"id": "0f2b1e3c", "event": "{}", "target": "https://example.com/merge"}},
+                "linked_account": {{
+                    "id": "0496d4c2-42e6-4072-80b3-7b69bfdc76fd",
+                    "integration": "BambooHR",
+                    "category": "{}",
+                    "end_user_origin_id": "1"
+                }},
+                "data": {}
+            }}"#,
+            event, category, data
+        )
+    }
+
+    #[test]
+    fn it_accepts_a_valid_signature() {
+        let verifier = WebhookVerifier::new(KEY);
+        verifier
+            .verify(
+                LINKED.as_bytes(),
+                Some("sV3R965kRV-MB3pr8W0AZ6E-uHHdvi_vj3VgurYqrko="),
+            )
+            .unwrap();
+
+        let payload = verifier
+            .parse(LINKED.as_bytes(), Some(&sign(LINKED)))
+            .unwrap();
+        assert_eq!(payload.event, WebhookEvent::AccountLinked);
+    }
+
+    #[test]
+    fn it_rejects_bad_signatures() {
+        let verifier = WebhookVerifier::new(KEY);
+        let tampered = LINKED.replace("linked", "account_deleted");
+
+        let err = verifier
+            .verify(tampered.as_bytes(), Some(&sign(LINKED)))
+            .unwrap_err();
+        assert!(matches!(err, WebhookError::InvalidSignature));
+
+        let err = verifier
+            .verify(LINKED.as_bytes(), Some("not base64!"))
+            .unwrap_err();
+        assert!(matches!(err, WebhookError::InvalidSignature));
+
+        let err = verifier.parse(LINKED.as_bytes(), None).unwrap_err();
+        assert!(err.is_unauthorized());
+        assert!(matches!(err, WebhookError::MissingSignature));
+    }
+
+    #[test]
+    fn it_accepts_a_signature_without_padding() {
+        let signature = sign(LINKED);
+        assert!(signature.ends_with('='));
+
+        WebhookVerifier::new(KEY)
+            .verify(LINKED.as_bytes(), Some(signature.trim_end_matches('=')))
+            .unwrap();
+    }
+
+    #[test]
+    fn it_types_changed_models() {
+        let body = changed(
+            "hris",
+            "BankInfo.changed",
+            r#"{"id": "fd1e0fb5-8f92-4ec9-9f32-179cf732867d", "bank_name": "Chase", "remote_was_deleted": false}"#,
+        );
+
+        let payload = WebhookPayload::from_slice(body.as_bytes()).unwrap();
+
+        assert_eq!(payload.hook.id, Some("0f2b1e3c".to_string()));
+        assert_eq!(
+            payload.linked_account.unwrap().integration,
+            Some("BambooHR".to_string())
+        );
+        match payload.event {
+            WebhookEvent::Model {
+                action: ChangeAction::Changed,
+                model: CommonModel::Hris(HrisModel::BankInfo(bank_info)),
+            } => {
+                let expected = hris::bank_info::BankInfoModelBuilder::default()
+                    .id("fd1e0fb5-8f92-4ec9-9f32-179cf732867d")
+                    .bank_name("Chase")
+                    .remote_was_deleted(false)
+                    .build()
+                    .unwrap();
+                assert_eq!(*bank_info, expected);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn it_prefers_the_category_of_the_event() {
+        let body = changed(
+            "hris",
+            "ticketing.Ticket.added",
+            r#"{"id": "0958cbc6-6040-430a-848e-aafacbadf4ae", "name": "Please add more integrations", "remote_was_deleted": false}"#,
+        );
+
+        let payload = WebhookPayload::from_slice(body.as_bytes()).unwrap();
+
+        assert!(matches!(
+            payload.event,
+            WebhookEvent::Model {
+                action: ChangeAction::Added,
+                model: CommonModel::Ticketing(TicketingModel::Ticket(_)),
+            }
+        ));
+    }
+
+    #[test]
+    fn it_parses_sync_notifications() {
+        let body = changed(
+            "ats",
+            "LinkedAccount.sync_completed",
+            r#"{
+                "integration_name": "Greenhouse",
+                "linked_account_id": "0496d4c2-42e6-4072-80b3-7b69bfdc76fd",
+                "sync_status": {"model_name": "Candidate", "model_id": "ats.Candidate", "status": "DONE", "is_initial_sync": true}
+            }"#,
+        );
+
+        let payload = WebhookPayload::from_slice(body.as_bytes()).unwrap();
+
+        match payload.event {
+            WebhookEvent::SyncCompleted(sync) => {
+                assert_eq!(sync.integration_name, Some("Greenhouse".to_string()));
+                assert_eq!(sync.sync_status.unwrap().model_id, "ats.Candidate");
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[test]
+    fn it_keeps_unknown_events() {
+        let body = changed("hris", "Paystub.changed", r#"{"id": "1"}"#);
+
+        let payload = WebhookPayload::from_slice(body.as_bytes()).unwrap();
+
+        assert_eq!(
+            payload.event,
+            WebhookEvent::Other {
+                event: "Paystub.changed".to_string(),
+                data: serde_json::json!({"id": "1"}),
+            }
+        );
+    }
+
+    #[test]
+    fn it_keeps_unknown_models_of_a_verified_webhook() {
+        let body = changed(
+            "hris",
+            "hris.Spaceship.changed",
+            r#"{"id": "1", "name": "Endurance"}"#,
+        );
+
+        let payload = WebhookVerifier::new(KEY)
+            .parse(body.as_bytes(), Some(&sign(&body)))
+            .unwrap();
+
+        assert_eq!(
+            payload.event,
+            WebhookEvent::Other {
+                event: "hris.Spaceship.changed".to_string(),
+                data: serde_json::json!({"id": "1", "name": "Endurance"}),
+            }
+        );
+    }
+
+    #[test]
+    fn it_keeps_models_that_do_not_decode() {
+        let body = changed("hris", "Employee.added", r#"{"remote_id": "19202938"}"#);
+
+        let payload = WebhookPayload::from_slice(body.as_bytes()).unwrap();
+
+        assert_eq!(
+            payload.event,
+            WebhookEvent::Other {
+                event: "Employee.added".to_string(),
+                data: serde_json::json!({"remote_id": "19202938"}),
+            }
+        );
+    }
+
+    #[test]
+    fn it_reports_bodies_that_are_not_payloads() {
+        let err = WebhookPayload::from_slice(br#"{"data": {}}"#).unwrap_err();
+
+        assert!(matches!(err, WebhookError::Payload(_)));
+        assert!(!err.is_unauthorized());
+    }
+}
@@ -0,0 +1,102 @@
+//! An extractor and a ready-made router for receiving webhooks with axum.
+
+use std::future::Future;
+
+use ::axum::async_trait;
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use ::axum::routing::post;
+use ::axum::Router;
+
+use super::{WebhookError, WebhookPayload, WebhookVerifier, SIGNATURE_HEADER};
+
+/// A webhook whose signature has been verified, using the `WebhookVerifier` of the router state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Webhook(pub WebhookPayload);
+
+#[async_trait]
+impl<S> FromRequest<S> for Webhook
+where
+    S: Send + Sync,
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = WebhookError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let verifier = WebhookVerifier::from_ref(state);
+        let signature = request
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(|err| WebhookError::Body(err.into()))?;
+        verifier.parse(&body, signature.as_deref()).map(Webhook)
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = if self.is_unauthorized() {
+            StatusCode::UNAUTHORIZED
+        } else {
+            StatusCode::BAD_REQUEST
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// A router answering `POST /` that hands every verified webhook to `on_event` and answers
+/// `200` once it has returned. Nest it wherever Merge is configured to send webhooks.
+pub fn router<F, Fut>(verifier: WebhookVerifier, on_event: F) -> Router
+where
+    F: Fn(WebhookPayload) -> Fut + Clone + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    Router::new()
+        .route(
+            "/",
+            post(move |Webhook(payload): Webhook| async move {
+                on_event(payload).await;
+                StatusCode::OK
+            }),
+        )
+        .with_state(verifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhooks::WebhookEvent;
+    use ::axum::body::Body;
+
+    const BODY: &str = r#"{"hook":{"event":"LinkedAccount.linked"},"data":{}}"#;
+
+    fn request(signature: &str) -> Request {
+        Request::post("/")
+            .header(SIGNATURE_HEADER, signature)
+            .body(Body::from(BODY))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_verified_webhook() {
+        let verifier = WebhookVerifier::new("someSignatureKey");
+
+        let Webhook(payload) = Webhook::from_request(
+            request("sV3R965kRV-MB3pr8W0AZ6E-uHHdvi_vj3VgurYqrko="),
+            &verifier,
+        )
+        .await
+        .unwrap();
+        assert_eq!(payload.event, WebhookEvent::AccountLinked);
+
+        let rejection = Webhook::from_request(request("c29tZXRoaW5nIGVsc2U="), &verifier)
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,96 @@
+//! Receiving webhooks with a plain hyper server.
+
+use std::future::Future;
+
+use ::hyper::body::{Body, Bytes};
+use ::hyper::{Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full};
+
+use super::{WebhookError, WebhookPayload, WebhookVerifier, SIGNATURE_HEADER};
+
+/// Reads the whole body of `request` and verifies its signature before decoding it.
+pub async fn parse<B>(
+    verifier: &WebhookVerifier,
+    request: Request<B>,
+) -> Result<WebhookPayload, WebhookError>
+where
+    B: Body,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let signature = request
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = request
+        .into_body()
+        .collect()
+        .await
+        .map_err(|err| WebhookError::Body(err.into()))?
+        .to_bytes();
+    verifier.parse(&body, signature.as_deref())
+}
+
+/// Handles a webhook request end to end: `on_event` is called with every verified webhook, and
+/// the response is `200`, `401` for bad signatures or `400` for bodies that do not decode.
+pub async fn handle<B, F, Fut>(
+    verifier: &WebhookVerifier,
+    request: Request<B>,
+    on_event: F,
+) -> Response<Full<Bytes>>
+where
+    B: Body,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    F: FnOnce(WebhookPayload) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let (status, body) = match parse(verifier, request).await {
+        Ok(payload) => {
+            on_event(payload).await;
+            (StatusCode::OK, String::new())
+        }
+        Err(err) if err.is_unauthorized() => (StatusCode::UNAUTHORIZED, err.to_string()),
+        Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+    };
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhooks::WebhookEvent;
+
+    fn request(body: &'static str) -> Request<Full<Bytes>> {
+        Request::post("/")
+            .header(
+                SIGNATURE_HEADER,
+                "sV3R965kRV-MB3pr8W0AZ6E-uHHdvi_vj3VgurYqrko=",
+            )
+            .body(Full::new(Bytes::from(body)))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_handles_a_webhook() {
+        let verifier = WebhookVerifier::new("someSignatureKey");
+        let mut events = vec![];
+
+        let response = handle(
+            &verifier,
+            request(r#"{"hook":{"event":"LinkedAccount.linked"},"data":{}}"#),
+            |payload| {
+                events.push(payload.event);
+                async {}
+            },
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(events, vec![WebhookEvent::AccountLinked]);
+
+        let response = handle(&verifier, request("{}"), |_| async {}).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}